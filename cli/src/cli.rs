@@ -1,6 +1,6 @@
-use antegen_network_program::state::MAX_COMMISSION_RATE;
+use antegen_network_program::state::COMMISSION_RATE_LIMIT;
 use clap::{value_parser, Arg, ArgAction, ArgGroup, Command};
-use antegen_thread_program::state::{SerializableInstruction, Trigger};
+use antegen_thread_program::state::{FeeModel, SerializableInstruction, Trigger};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 
 use crate::parser::ProgramInfo;
@@ -11,15 +11,79 @@ pub enum CliCommand {
     Crontab {
         schedule: String,
     },
+    // Delegation
+    DelegationClaim {
+        worker_id: u64,
+        id: u64,
+    },
+    DelegationCreate {
+        worker_id: u64,
+    },
+    DelegationDeposit {
+        worker_id: u64,
+        id: u64,
+        amount: u64,
+    },
+    DelegationGet {
+        worker_id: u64,
+        id: u64,
+    },
+    DelegationUnbond {
+        worker_id: u64,
+        id: u64,
+        amount: u64,
+    },
+    DelegationWithdraw {
+        worker_id: u64,
+        id: u64,
+    },
     NetworkInitialize {},
-    NetworkThreadCreate { amount: u64 },
+    NetworkThreadCreate,
+    NetworkThreadSync {
+        output_format: Option<String>,
+    },
     NetworkConfigSet {
         admin: Option<Pubkey>,
+        batch_size: Option<u64>,
+        epoch_length: Option<u64>,
+        epoch_summaries: Option<bool>,
         epoch_thread: Option<Pubkey>,
+        fee_mint: Option<Pubkey>,
+        fee_price_feed: Option<Pubkey>,
+        hasher_interval: Option<u64>,
         hasher_thread: Option<Pubkey>,
+        heartbeat_timeout: Option<u64>,
+        max_commission_rate: Option<u64>,
+        min_commission_rate: Option<u64>,
+        min_stake: Option<u64>,
+        network_fee_bps: Option<u64>,
+        network_fee_recipient: Option<Pubkey>,
+        snapshot_retention: Option<u64>,
+        thread_funding: Option<u64>,
+        timelock: Option<u64>,
+        unbonding_period: Option<u64>,
         output_format: Option<String>,
     },
     NetworkConfigGet,
+    NetworkConfigMigrate,
+    NetworkEpochMigrate {
+        id: Option<u64>,
+    },
+    NetworkEpochStatus,
+    NetworkEpochSummary {
+        epoch: u64,
+    },
+    NetworkProposalCancel {
+        id: u64,
+        output_format: Option<String>,
+    },
+    NetworkProposalExecute {
+        id: u64,
+    },
+    NetworkProposalGet {
+        id: u64,
+    },
+    NetworkProposalList,
     Localnet {
         force_init: bool,
         clone_addresses: Vec<Pubkey>,
@@ -33,9 +97,14 @@ pub enum CliCommand {
         id: u64,
     },
     PoolList {},
+    PoolMigrate {
+        id: u64,
+    },
     PoolUpdate {
         id: u64,
-        size: u64,
+        rotation_period: Option<u64>,
+        size: Option<u64>,
+        output_format: Option<String>,
     },
     PoolRotate {
         id: u64,
@@ -43,6 +112,10 @@ pub enum CliCommand {
     // TODO Rename to Version. Use flags to filter by program.
     //      Default to listing all deployed program versions on the user's configured cluster.
     ThreadCrateInfo,
+    ThreadCheck {
+        id: Option<String>,
+        address: Option<Pubkey>,
+    },
     ThreadCreate {
         id: String,
         kickoff_instruction: SerializableInstruction,
@@ -62,6 +135,21 @@ pub enum CliCommand {
         id: Option<String>,
         address: Option<Pubkey>,
     },
+    ThreadInstructionInsert {
+        id: String,
+        index: u64,
+        instruction: SerializableInstruction,
+    },
+    ThreadInstructionMove {
+        id: String,
+        from: u64,
+        to: u64,
+    },
+    ThreadInstructionReplace {
+        id: String,
+        index: u64,
+        instruction: SerializableInstruction,
+    },
     ThreadPause {
         id: String,
     },
@@ -73,29 +161,76 @@ pub enum CliCommand {
     },
     ThreadUpdate {
         id: String,
+        fee_model: Option<FeeModel>,
+        fee_token_account: Option<Option<Pubkey>>,
+        pool_id: Option<u64>,
         rate_limit: Option<u64>,
         schedule: Option<String>,
     },
 
     // Registry
     RegistryGet,
-    RegistryReset,
+    RegistryReset {
+        output_format: Option<String>,
+    },
     RegistryUnlock,
 
     // Worker commands
+    WorkerClaim {
+        id: u64,
+    },
+    WorkerClose {
+        id: u64,
+    },
     WorkerCreate {
         signatory: Keypair,
     },
+    WorkerDeactivate {
+        id: u64,
+    },
     WorkerGet {
         id: u64,
     },
+    WorkerList,
+    WorkerMigrate {
+        id: u64,
+    },
+    WorkerSlash {
+        id: u64,
+        amount: u64,
+    },
+    WorkerStake {
+        id: u64,
+        amount: u64,
+    },
+    WorkerStats {
+        id: u64,
+    },
+    WorkerUnbond {
+        id: u64,
+        amount: u64,
+    },
+    WorkerWithdraw {
+        id: u64,
+    },
     WorkerUpdate {
         id: u64,
         commission_rate: Option<u64>,
+        metadata: WorkerMetadataArgs,
         signatory: Option<Keypair>,
     },
 }
 
+/// Worker metadata fields to update. An empty value clears the field.
+#[derive(Debug, PartialEq, Default)]
+pub struct WorkerMetadataArgs {
+    pub contact: Option<String>,
+    pub name: Option<String>,
+    pub plugin_version: Option<String>,
+    pub region: Option<String>,
+    pub website: Option<String>,
+}
+
 pub fn app() -> Command {
     Command::new("Antegen")
         .bin_name("antegen")
@@ -115,6 +250,139 @@ pub fn app() -> Command {
                         .help("The schedule to generate a cron table for"),
                 ),
         )
+        .subcommand(
+            Command::new("delegation")
+                .about("Manage stake delegated to workers")
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("claim")
+                        .about("Claim the fees a delegation has earned")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker the stake is delegated to"),
+                        )
+                        .arg(
+                            Arg::new("id")
+                                .index(2)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the delegation to claim from"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("create")
+                        .about("Create a new delegation to a worker")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to delegate to"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("deposit")
+                        .about("Deposit lamports into a delegation")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker the stake is delegated to"),
+                        )
+                        .arg(
+                            Arg::new("id")
+                                .index(2)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the delegation to deposit into"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .index(3)
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(true)
+                                .help("The number of lamports to delegate"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("get")
+                        .about("Lookup a delegation")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker the stake is delegated to"),
+                        )
+                        .arg(
+                            Arg::new("id")
+                                .index(2)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the delegation to lookup"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("unbond")
+                        .about("Start unbonding lamports from a delegation")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker the stake is delegated to"),
+                        )
+                        .arg(
+                            Arg::new("id")
+                                .index(2)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the delegation to unbond from"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .index(3)
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(true)
+                                .help("The number of lamports to unbond"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("withdraw")
+                        .about("Withdraw a delegation's unbonded lamports")
+                        .arg(
+                            Arg::new("worker_id")
+                                .index(1)
+                                .value_name("WORKER_ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker the stake is delegated to"),
+                        )
+                        .arg(
+                            Arg::new("id")
+                                .index(2)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the delegation to withdraw from"),
+                        ),
+                ),
+        )
         .subcommand(
             Command::new("network")
                 .about("Manage the Antegen Network Program")
@@ -125,6 +393,9 @@ pub fn app() -> Command {
                     .subcommand(Command::new("get")
                         .about("Get current config settings")
                     )
+                    .subcommand(Command::new("migrate")
+                        .about("Migrate a config created before the current account layout")
+                    )
                     .subcommand(
                         Command::new("set")
                             .about("Set a config setting")
@@ -134,6 +405,20 @@ pub fn app() -> Command {
                                     .value_name("ADMIN")
                                     .num_args(1)
                             )
+                            .arg(
+                                Arg::new("batch_size")
+                                    .long("batch-size")
+                                    .value_name("WORKERS")
+                                    .num_args(1)
+                                    .help("The maximum number of workers the epoch jobs process per instruction")
+                            )
+                            .arg(
+                                Arg::new("epoch_length")
+                                    .long("epoch-length")
+                                    .value_name("SECONDS")
+                                    .num_args(1)
+                                    .help("The number of seconds between epochs")
+                            )
                             .arg(
                                 Arg::new("epoch_thread")
                                     .long("epoch-thread")
@@ -141,6 +426,37 @@ pub fn app() -> Command {
                                     .value_name("EPOCH_THREAD")
                                     .num_args(1)
                             )
+                            .arg(
+                                Arg::new("epoch_summaries")
+                                    .long("epoch-summaries")
+                                    .value_name("BOOL")
+                                    .num_args(1)
+                                    .value_parser(value_parser!(bool))
+                                    .help("Whether to keep a summary of each epoch's fee distribution after its snapshot is deleted")
+                            )
+                            .arg(
+                                Arg::new("fee_mint")
+                                    .long("fee-mint")
+                                    .value_name("FEE_MINT")
+                                    .num_args(1)
+                                    .requires("fee_price_feed")
+                                    .help("The token mint threads may pay worker fees in (not yet accepted by the network)")
+                            )
+                            .arg(
+                                Arg::new("fee_price_feed")
+                                    .long("fee-price-feed")
+                                    .value_name("FEE_PRICE_FEED")
+                                    .num_args(1)
+                                    .requires("fee_mint")
+                                    .help("The Pyth feed pricing SOL in the fee token")
+                            )
+                            .arg(
+                                Arg::new("hasher_interval")
+                                    .long("hasher-interval")
+                                    .value_name("SECONDS")
+                                    .num_args(1)
+                                    .help("The number of seconds between registry nonce hashes")
+                            )
                             .arg(
                                 Arg::new("hasher_thread")
                                     .long("hasher-thread")
@@ -148,6 +464,76 @@ pub fn app() -> Command {
                                     .value_name("HASHER_THREAD")
                                     .num_args(1)
                             )
+                            .arg(
+                                Arg::new("heartbeat_timeout")
+                                    .long("heartbeat-timeout")
+                                    .value_name("SLOTS")
+                                    .num_args(1)
+                                    .help("The number of slots without a heartbeat after which workers are evicted from pools (0 disables eviction)")
+                            )
+                            .arg(
+                                Arg::new("max_commission_rate")
+                                    .long("max-commission-rate")
+                                    .value_name("PERCENT")
+                                    .num_args(1)
+                                    .help("The highest commission rate workers may set")
+                            )
+                            .arg(
+                                Arg::new("min_commission_rate")
+                                    .long("min-commission-rate")
+                                    .value_name("PERCENT")
+                                    .num_args(1)
+                                    .help("The lowest commission rate workers may set")
+                            )
+                            .arg(
+                                Arg::new("min_stake")
+                                    .long("min-stake")
+                                    .value_name("LAMPORTS")
+                                    .num_args(1)
+                                    .help("The minimum stake a worker must bond to join a pool")
+                            )
+                            .arg(
+                                Arg::new("network_fee_bps")
+                                    .long("network-fee-bps")
+                                    .value_name("BPS")
+                                    .num_args(1)
+                                    .help("The share of worker fees, in basis points, paid to the network")
+                            )
+                            .arg(
+                                Arg::new("network_fee_recipient")
+                                    .long("network-fee-recipient")
+                                    .value_name("ADDRESS")
+                                    .num_args(1)
+                                    .help("The account network fees and slashed stake are paid to")
+                            )
+                            .arg(
+                                Arg::new("snapshot_retention")
+                                    .long("snapshot-retention")
+                                    .value_name("SNAPSHOTS")
+                                    .num_args(1)
+                                    .help("The number of past epochs whose snapshots are kept before being deleted")
+                            )
+                            .arg(
+                                Arg::new("thread_funding")
+                                    .long("thread-funding")
+                                    .value_name("LAMPORTS")
+                                    .num_args(1)
+                                    .help("The number of lamports the network threads are funded with")
+                            )
+                            .arg(
+                                Arg::new("timelock")
+                                    .long("timelock")
+                                    .value_name("SLOTS")
+                                    .num_args(1)
+                                    .help("The number of slots proposed admin changes wait before they can be executed")
+                            )
+                            .arg(
+                                Arg::new("unbonding_period")
+                                    .long("unbonding-period")
+                                    .value_name("SLOTS")
+                                    .num_args(1)
+                                    .help("The number of slots unbonded stake is locked for")
+                            )
                             .arg(
                                 Arg::new("output")
                                     .long("output")
@@ -158,28 +544,116 @@ pub fn app() -> Command {
                             )
                             .group(
                                 ArgGroup::new("config_settings")
-                                    .args(&["admin", "epoch_thread", "hasher_thread"])
+                                    .args(&["admin", "batch_size", "epoch_length", "epoch_summaries", "epoch_thread", "fee_mint", "fee_price_feed", "hasher_interval", "hasher_thread", "heartbeat_timeout", "max_commission_rate", "min_commission_rate", "min_stake", "network_fee_bps", "network_fee_recipient", "snapshot_retention", "thread_funding", "timelock", "unbonding_period"])
                                     .multiple(true),
                             ),
                     )
                 )
+                .subcommand(
+                    Command::new("epoch")
+                        .about("Inspect the epoch thread's jobs")
+                        .arg_required_else_help(true)
+                        .subcommand(
+                            Command::new("migrate")
+                                .about("Migrate a snapshot and its frames taken before the current account layout")
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(false)
+                                        .help("The ID of the snapshot to migrate, defaulting to the current epoch's"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("status")
+                                .about("Show the phase and progress of the epoch jobs")
+                        )
+                        .subcommand(
+                            Command::new("summary")
+                                .about("Lookup the fee distribution summary of an epoch")
+                                .arg(
+                                    Arg::new("epoch")
+                                        .index(1)
+                                        .value_name("EPOCH")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The epoch to lookup"),
+                                ),
+                        )
+                )
                 .subcommand(
                     Command::new("initialize")
                         .about("Initialize the Network Program")
                 )
+                .subcommand(
+                    Command::new("proposal")
+                        .about("Manage timelocked admin proposals")
+                        .arg_required_else_help(true)
+                        .subcommand(
+                            Command::new("cancel")
+                                .about("Cancel a pending proposal")
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The ID of the proposal to cancel"),
+                                )
+                                .arg(
+                                    Arg::new("output")
+                                        .long("output")
+                                        .short('o')
+                                        .value_name("FORMAT")
+                                        .value_parser(["base58"])
+                                        .help("Output format instead of submitting transaction"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("execute")
+                                .about("Execute a proposal whose timelock has elapsed")
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The ID of the proposal to execute"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("get")
+                                .about("Lookup a proposal")
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The ID of the proposal to lookup"),
+                                ),
+                        )
+                        .subcommand(Command::new("list").about("List the pending proposals"))
+                )
                 .subcommand(
                     Command::new("threads")
                         .about("Manage Network threads")
                         .subcommand(
                             Command::new("create")
-                                .about("Create Epoch and Hasher threads")
+                                .about("Create Epoch and Hasher threads, scheduled and funded per the network config")
+                        )
+                        .subcommand(
+                            Command::new("sync")
+                                .about("Update the Epoch and Hasher threads' schedules and funding to match the network config")
                                 .arg(
-                                    Arg::new("amount")
-                                        .long("amount")
-                                        .help("Amount in SOL to deposit")
-                                        .value_parser(value_parser!(f64))
-                                        .default_value("1.0")
-                                )
+                                    Arg::new("output")
+                                        .long("output")
+                                        .short('o')
+                                        .value_name("FORMAT")
+                                        .value_parser(["base58"])
+                                        .help("Output format instead of submitting transaction"),
+                                ),
                         )
                 )
         )
@@ -277,6 +751,19 @@ pub fn app() -> Command {
                         ),
                 )
                 .subcommand(Command::new("list").about("List the pools"))
+                .subcommand(
+                    Command::new("migrate")
+                        .about("Migrate a pool created before the current account layout")
+                        .arg_required_else_help(true)
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(false)
+                                .help("The ID of the pool to migrate"),
+                        ),
+                )
                 .subcommand(
                     Command::new("update")
                         .about("Update a pool")
@@ -289,6 +776,15 @@ pub fn app() -> Command {
                                 .required(false)
                                 .help("The ID of the pool to update"),
                         )
+                        .arg(
+                            Arg::new("rotation_period")
+                                .long("rotation-period")
+                                .short('r')
+                                .value_name("SLOTS")
+                                .num_args(1)
+                                .required(false)
+                                .help("The number of slots between rotations of a full pool"),
+                        )
                         .arg(
                             Arg::new("size")
                                 .long("size")
@@ -297,6 +793,14 @@ pub fn app() -> Command {
                                 .num_args(1)
                                 .required(false)
                                 .help("The size of the pool"),
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .long("output")
+                                .short('o')
+                                .value_name("FORMAT")
+                                .value_parser(["base58"])
+                                .help("Output format instead of submitting transaction"),
                         ),
                 )
                 .subcommand(
@@ -321,6 +825,28 @@ pub fn app() -> Command {
                     Command::new("crate-info")
                         .about("Crate Information")
                 )
+                .subcommand(
+                    Command::new("check")
+                        .about("Check whether a thread is due")
+                        .arg_required_else_help(true)
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .required(false)
+                                .value_name("ID")
+                                .num_args(1)
+                                .help("The label of the thread to check (only works if you \
+                                are the signer of that thread)")
+                        )
+                        .arg(
+                            Arg::new("address")
+                                .short('k')
+                                .long("address")
+                                .value_name("ADDRESS")
+                                .num_args(1)
+                                .help("The address of the thread to check"),
+                        )
+                )
                 .subcommand(
                     Command::new("create")
                         .about("Create an new thread")
@@ -359,6 +885,13 @@ pub fn app() -> Command {
                                 .num_args(1)
                                 .help("A cron-based trigger"),
                         )
+                        .arg(
+                            Arg::new("every_epoch")
+                                .long("every-epoch")
+                                .value_name("OFFSET_SLOTS")
+                                .num_args(1)
+                                .help("A recurring epoch trigger, firing the given number of slots into each epoch"),
+                        )
                         .arg(
                             Arg::new("immediate")
                                 .long("immediate")
@@ -367,7 +900,7 @@ pub fn app() -> Command {
                         )
                         .group(
                             ArgGroup::new("trigger")
-                                .args(&["account", "cron", "immediate"])
+                                .args(&["account", "cron", "every_epoch", "immediate"])
                                 .required(true),
                         ),
                 )
@@ -443,6 +976,98 @@ pub fn app() -> Command {
                                 .help("The address of the thread to lookup"),
                         )
                 )
+                .subcommand(
+                    Command::new("instruction")
+                        .about("Edit a thread's instruction set")
+                        .arg_required_else_help(true)
+                        .subcommand(
+                            Command::new("insert")
+                                .about("Insert an instruction at an index")
+                                .arg_required_else_help(true)
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The id of the thread to edit"),
+                                )
+                                .arg(
+                                    Arg::new("index")
+                                        .long("index")
+                                        .value_name("INDEX")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The index to insert the instruction at"),
+                                )
+                                .arg(
+                                    Arg::new("instruction")
+                                        .long("instruction")
+                                        .value_name("FILEPATH")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("Filepath to a description of the instruction"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("move")
+                                .about("Move an instruction from one index to another")
+                                .arg_required_else_help(true)
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The id of the thread to edit"),
+                                )
+                                .arg(
+                                    Arg::new("from")
+                                        .long("from")
+                                        .value_name("INDEX")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The current index of the instruction"),
+                                )
+                                .arg(
+                                    Arg::new("to")
+                                        .long("to")
+                                        .value_name("INDEX")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The new index of the instruction"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("replace")
+                                .about("Replace the instruction at an index")
+                                .arg_required_else_help(true)
+                                .arg(
+                                    Arg::new("id")
+                                        .index(1)
+                                        .value_name("ID")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The id of the thread to edit"),
+                                )
+                                .arg(
+                                    Arg::new("index")
+                                        .long("index")
+                                        .value_name("INDEX")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("The index of the instruction to replace"),
+                                )
+                                .arg(
+                                    Arg::new("instruction")
+                                        .long("instruction")
+                                        .value_name("FILEPATH")
+                                        .num_args(1)
+                                        .required(true)
+                                        .help("Filepath to a description of the instruction"),
+                                ),
+                        ),
+                )
                 .subcommand(
                     Command::new("pause")
                         .about("Pause a thread")
@@ -488,6 +1113,48 @@ pub fn app() -> Command {
                                 .required(false)
                                 .help("The id of the thread to lookup"),
                         )
+                        .arg(
+                            Arg::new("fee_model")
+                                .long("fee_model")
+                                .value_name("FEE_MODEL")
+                                .num_args(1)
+                                .required(false)
+                                .value_parser(["flat", "compute", "flat-plus-compute"])
+                                .requires_if("compute", "lamports_per_1k_cu")
+                                .requires_if("flat-plus-compute", "lamports_per_1k_cu")
+                                .help("How the worker fee is calculated for each exec"),
+                        )
+                        .arg(
+                            Arg::new("lamports_per_1k_cu")
+                                .long("lamports_per_1k_cu")
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(false)
+                                .help("The lamports paid to workers per 1,000 compute units"),
+                        )
+                        .arg(
+                            Arg::new("fee_token_account")
+                                .long("fee_token_account")
+                                .value_name("ADDRESS")
+                                .num_args(1)
+                                .required(false)
+                                .conflicts_with("lamport_fees")
+                                .help("A token account owned by the thread to pay worker fees from"),
+                        )
+                        .arg(
+                            Arg::new("lamport_fees")
+                                .long("lamport_fees")
+                                .action(ArgAction::SetTrue)
+                                .help("Pay worker fees in lamports rather than from a token account"),
+                        )
+                        .arg(
+                            Arg::new("pool")
+                                .long("pool")
+                                .value_name("POOL_ID")
+                                .num_args(1)
+                                .required(false)
+                                .help("The id of the worker pool that should execute this thread"),
+                        )
                         .arg(
                             Arg::new("rate_limit")
                                 .long("rate_limit")
@@ -515,7 +1182,18 @@ pub fn app() -> Command {
                 .about("Manage the Antegen network registry")
                 .arg_required_else_help(true)
                 .subcommand(Command::new("get").about("Lookup the registry"))
-                .subcommand(Command::new("reset").about("Manually reset the registry"))
+                .subcommand(
+                    Command::new("reset")
+                        .about("Manually reset the registry")
+                        .arg(
+                            Arg::new("output")
+                                .long("output")
+                                .short('o')
+                                .value_name("FORMAT")
+                                .value_parser(["base58"])
+                                .help("Output format instead of submitting transaction"),
+                        ),
+                )
                 .subcommand(Command::new("unlock").about("Manually unlock the registry")),
         )
         .subcommand(
@@ -538,6 +1216,42 @@ pub fn app() -> Command {
                                 .help("Filepath to the worker's signatory keypair"),
                         ),
                 )
+                .subcommand(
+                    Command::new("claim")
+                        .about("Claim a worker's commission from the fees collected since the last distribution")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to claim for"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("close")
+                        .about("Close a deactivated worker and reclaim its commission and rent")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to close"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("deactivate")
+                        .about("Remove a worker from every pool and stop it being snapshotted")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to deactivate"),
+                        ),
+                )
                 .subcommand(
                     Command::new("get")
                         .about("Lookup a worker on the Antegen network")
@@ -550,6 +1264,105 @@ pub fn app() -> Command {
                                 .help("The ID of the worker to lookup"),
                         ),
                 )
+                .subcommand(
+                    Command::new("list").about("List the workers and their published metadata"),
+                )
+                .subcommand(
+                    Command::new("migrate")
+                        .about("Migrate a worker created before the current account layout")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to migrate"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("slash")
+                        .about("Propose slashing a worker's stake for misbehaviour")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to slash"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .index(2)
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(true)
+                                .help("The number of lamports to slash"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("stake")
+                        .about("Bond lamports to a worker")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to stake"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .index(2)
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(true)
+                                .help("The number of lamports to bond"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("stats")
+                        .about("Show the work a worker has performed this epoch and last epoch")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to lookup"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("unbond")
+                        .about("Start unbonding lamports from a worker")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to unbond from"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .index(2)
+                                .value_name("LAMPORTS")
+                                .num_args(1)
+                                .required(true)
+                                .help("The number of lamports to unbond"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("withdraw")
+                        .about("Withdraw a worker's unbonded lamports")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .value_name("ID")
+                                .num_args(1)
+                                .required(true)
+                                .help("The ID of the worker to withdraw from"),
+                        ),
+                )
                 .subcommand(
                     Command::new("update")
                         .about("Update a worker")
@@ -567,8 +1380,8 @@ pub fn app() -> Command {
                                 .num_args(1)
                                 .required(false)
                                 .value_parser(value_parser!(u64))
-                                .value_parser(value_parser!(u64).range(0..=MAX_COMMISSION_RATE))
-                                .help("The commission rate (0-90)"),
+                                .value_parser(value_parser!(u64).range(0..=COMMISSION_RATE_LIMIT))
+                                .help("The commission rate, within the network's configured range"),
                         )
                         .arg(
                             Arg::new("signatory_keypair")
@@ -578,6 +1391,46 @@ pub fn app() -> Command {
                                 .num_args(1)
                                 .required(false)
                                 .help("Filepath to the worker's new signatory keypair"),
+                        )
+                        .arg(
+                            Arg::new("contact")
+                                .long("contact")
+                                .value_name("CONTACT")
+                                .num_args(1)
+                                .required(false)
+                                .help("How to reach the worker's operator, or an empty value to clear it"),
+                        )
+                        .arg(
+                            Arg::new("name")
+                                .long("name")
+                                .value_name("NAME")
+                                .num_args(1)
+                                .required(false)
+                                .help("The worker's display name, or an empty value to clear it"),
+                        )
+                        .arg(
+                            Arg::new("plugin_version")
+                                .long("plugin-version")
+                                .value_name("PLUGIN_VERSION")
+                                .num_args(1)
+                                .required(false)
+                                .help("The version of the plugin the worker runs, or an empty value to clear it"),
+                        )
+                        .arg(
+                            Arg::new("region")
+                                .long("region")
+                                .value_name("REGION")
+                                .num_args(1)
+                                .required(false)
+                                .help("The region the worker is hosted in, or an empty value to clear it"),
+                        )
+                        .arg(
+                            Arg::new("website")
+                                .long("website")
+                                .value_name("WEBSITE")
+                                .num_args(1)
+                                .required(false)
+                                .help("The worker operator's website, or an empty value to clear it"),
                         ),
                 ),
         )
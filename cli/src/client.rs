@@ -8,6 +8,7 @@ use solana_sdk::{
     commitment_config::CommitmentConfig,
     hash::Hash,
     instruction::Instruction,
+    message::Message,
     program_error::ProgramError,
     pubkey::Pubkey,
     signature::{Keypair, Signature, Signer},
@@ -101,6 +102,14 @@ impl Client {
         Ok(signature)
     }
 
+    /// Builds an unsigned transaction for the fee payer and encodes it in base58, so it can be signed elsewhere.
+    pub fn export_transaction(&self, ixs: &[Instruction], fee_payer: &Pubkey) -> ClientResult<String> {
+        let blockhash = self.client.get_latest_blockhash()?;
+        let mut tx = Transaction::new_unsigned(Message::new(ixs, Some(fee_payer)));
+        tx.message.recent_blockhash = blockhash;
+        Ok(bs58::encode(bincode::serialize(&tx).unwrap()).into_string())
+    }
+
     pub fn send_and_confirm<T: Signers>(
         &self,
         ixs: &[Instruction],
@@ -1,13 +1,16 @@
 use std::{convert::TryFrom, fs, path::PathBuf, str::FromStr};
 
 use clap::ArgMatches;
-use antegen_thread_program::state::{SerializableAccount, SerializableInstruction, Trigger};
+use antegen_thread_program::state::{FeeModel, SerializableAccount, SerializableInstruction, Trigger};
 use serde::{Deserialize as JsonDeserialize, Serialize as JsonSerialize};
 use solana_sdk::{
-    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::{read_keypair_file, Keypair}, signer::Signer
+    pubkey::Pubkey, signature::{read_keypair_file, Keypair}, signer::Signer
 };
 
-use crate::{cli::CliCommand, errors::CliError};
+use crate::{
+    cli::{CliCommand, WorkerMetadataArgs},
+    errors::CliError,
+};
 
 impl TryFrom<&ArgMatches> for CliCommand {
     type Error = CliError;
@@ -15,6 +18,7 @@ impl TryFrom<&ArgMatches> for CliCommand {
     fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
         match matches.subcommand() {
             Some(("crontab", matches)) => parse_crontab_command(matches),
+            Some(("delegation", matches)) => parse_delegation_command(matches),
             Some(("network", matches)) => parse_network_command(matches),
             Some(("localnet", matches)) => parse_bpf_command(matches),
             Some(("pool", matches)) => parse_pool_command(matches),
@@ -93,30 +97,104 @@ fn parse_crontab_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     })
 }
 
+fn parse_delegation_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
+    match matches.subcommand() {
+        Some(("claim", matches)) => Ok(CliCommand::DelegationClaim {
+            worker_id: parse_u64("worker_id", matches)?,
+            id: parse_u64("id", matches)?,
+        }),
+        Some(("create", matches)) => Ok(CliCommand::DelegationCreate {
+            worker_id: parse_u64("worker_id", matches)?,
+        }),
+        Some(("deposit", matches)) => Ok(CliCommand::DelegationDeposit {
+            worker_id: parse_u64("worker_id", matches)?,
+            id: parse_u64("id", matches)?,
+            amount: parse_u64("amount", matches)?,
+        }),
+        Some(("get", matches)) => Ok(CliCommand::DelegationGet {
+            worker_id: parse_u64("worker_id", matches)?,
+            id: parse_u64("id", matches)?,
+        }),
+        Some(("unbond", matches)) => Ok(CliCommand::DelegationUnbond {
+            worker_id: parse_u64("worker_id", matches)?,
+            id: parse_u64("id", matches)?,
+            amount: parse_u64("amount", matches)?,
+        }),
+        Some(("withdraw", matches)) => Ok(CliCommand::DelegationWithdraw {
+            worker_id: parse_u64("worker_id", matches)?,
+            id: parse_u64("id", matches)?,
+        }),
+        _ => Err(CliError::CommandNotRecognized(
+            matches.subcommand().unwrap().0.into(),
+        )),
+    }
+}
+
 fn parse_network_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
         Some(("config", config_matches)) => match config_matches.subcommand() {
             Some(("set", matches)) => Ok(CliCommand::NetworkConfigSet {
                 admin: parse_pubkey("admin", matches).ok(),
+                batch_size: parse_u64("batch_size", matches).ok(),
+                epoch_length: parse_u64("epoch_length", matches).ok(),
+                epoch_summaries: matches.get_one::<bool>("epoch_summaries").copied(),
                 epoch_thread: parse_pubkey("epoch_thread", matches).ok(),
+                fee_mint: parse_pubkey("fee_mint", matches).ok(),
+                fee_price_feed: parse_pubkey("fee_price_feed", matches).ok(),
+                hasher_interval: parse_u64("hasher_interval", matches).ok(),
                 hasher_thread: parse_pubkey("hasher_thread", matches).ok(),
+                heartbeat_timeout: parse_u64("heartbeat_timeout", matches).ok(),
+                max_commission_rate: parse_u64("max_commission_rate", matches).ok(),
+                min_commission_rate: parse_u64("min_commission_rate", matches).ok(),
+                min_stake: parse_u64("min_stake", matches).ok(),
+                network_fee_bps: parse_u64("network_fee_bps", matches).ok(),
+                network_fee_recipient: parse_pubkey("network_fee_recipient", matches).ok(),
+                snapshot_retention: parse_u64("snapshot_retention", matches).ok(),
+                thread_funding: parse_u64("thread_funding", matches).ok(),
+                timelock: parse_u64("timelock", matches).ok(),
+                unbonding_period: parse_u64("unbonding_period", matches).ok(),
                 output_format: parse_string("output", matches).ok(),
             }),
             Some(("get", _)) => Ok(CliCommand::NetworkConfigGet {}),
+            Some(("migrate", _)) => Ok(CliCommand::NetworkConfigMigrate {}),
+            _ => Err(CliError::CommandNotRecognized(
+                matches.subcommand().unwrap().0.into(),
+            )),
+        },
+        Some(("epoch", epoch_matches)) => match epoch_matches.subcommand() {
+            Some(("migrate", matches)) => Ok(CliCommand::NetworkEpochMigrate {
+                id: parse_u64("id", matches).ok(),
+            }),
+            Some(("status", _)) => Ok(CliCommand::NetworkEpochStatus {}),
+            Some(("summary", matches)) => Ok(CliCommand::NetworkEpochSummary {
+                epoch: parse_u64("epoch", matches)?,
+            }),
             _ => Err(CliError::CommandNotRecognized(
                 matches.subcommand().unwrap().0.into(),
             )),
         },
         Some(("initialize", _)) => Ok(CliCommand::NetworkInitialize {}),
+        Some(("proposal", proposal_matches)) => match proposal_matches.subcommand() {
+            Some(("cancel", matches)) => Ok(CliCommand::NetworkProposalCancel {
+                id: parse_u64("id", matches)?,
+                output_format: parse_string("output", matches).ok(),
+            }),
+            Some(("execute", matches)) => Ok(CliCommand::NetworkProposalExecute {
+                id: parse_u64("id", matches)?,
+            }),
+            Some(("get", matches)) => Ok(CliCommand::NetworkProposalGet {
+                id: parse_u64("id", matches)?,
+            }),
+            Some(("list", _)) => Ok(CliCommand::NetworkProposalList {}),
+            _ => Err(CliError::CommandNotRecognized(
+                matches.subcommand().unwrap().0.into(),
+            )),
+        },
         Some(("threads", thread_matches)) => match thread_matches.subcommand() {
-            Some(("create", create_matches)) => {
-                let sol_amount = create_matches
-                    .get_one::<f64>("amount")
-                    .copied()
-                    .unwrap_or(1.0);
-                let amount = (sol_amount * LAMPORTS_PER_SOL as f64) as u64;
-                Ok(CliCommand::NetworkThreadCreate { amount })
-            },
+            Some(("create", _)) => Ok(CliCommand::NetworkThreadCreate {}),
+            Some(("sync", matches)) => Ok(CliCommand::NetworkThreadSync {
+                output_format: parse_string("output", matches).ok(),
+            }),
             _ => Err(CliError::CommandNotRecognized(
                 matches.subcommand().unwrap().0.into(),
             )),
@@ -134,12 +212,17 @@ fn parse_pool_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
         }),
         Some(("update", matches)) => Ok(CliCommand::PoolUpdate {
             id: parse_u64("id", matches)?,
-            size: parse_u64("size", matches)?,
+            rotation_period: parse_u64("rotation_period", matches).ok(),
+            size: parse_u64("size", matches).ok(),
+            output_format: parse_string("output", matches).ok(),
         }),
         Some(("rotate", matches)) => Ok(CliCommand::PoolRotate {
             id: parse_u64("id", matches)?,
         }),
         Some(("list", _)) => Ok(CliCommand::PoolList {}),
+        Some(("migrate", matches)) => Ok(CliCommand::PoolMigrate {
+            id: parse_u64("id", matches)?,
+        }),
         _ => Err(CliError::CommandNotRecognized(
             matches.subcommand().unwrap().0.into(),
         )),
@@ -149,6 +232,10 @@ fn parse_pool_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
 fn parse_thread_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
         Some(("crate-info", _)) => Ok(CliCommand::ThreadCrateInfo {}),
+        Some(("check", matches)) => Ok(CliCommand::ThreadCheck {
+            id: parse_string("id", matches).ok(),
+            address: parse_pubkey("address", matches).ok(),
+        }),
         Some(("create", matches)) => Ok(CliCommand::ThreadCreate {
             id: parse_string("id", matches)?,
             kickoff_instruction: parse_instruction_file("kickoff_instruction", matches)?,
@@ -168,6 +255,26 @@ fn parse_thread_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
             id: parse_string("id", matches).ok(),
             address: parse_pubkey("address", matches).ok(),
         }),
+        Some(("instruction", matches)) => match matches.subcommand() {
+            Some(("insert", matches)) => Ok(CliCommand::ThreadInstructionInsert {
+                id: parse_string("id", matches)?,
+                index: parse_u64("index", matches)?,
+                instruction: parse_instruction_file("instruction", matches)?,
+            }),
+            Some(("move", matches)) => Ok(CliCommand::ThreadInstructionMove {
+                id: parse_string("id", matches)?,
+                from: parse_u64("from", matches)?,
+                to: parse_u64("to", matches)?,
+            }),
+            Some(("replace", matches)) => Ok(CliCommand::ThreadInstructionReplace {
+                id: parse_string("id", matches)?,
+                index: parse_u64("index", matches)?,
+                instruction: parse_instruction_file("instruction", matches)?,
+            }),
+            _ => Err(CliError::CommandNotRecognized(
+                matches.subcommand().unwrap().0.into(),
+            )),
+        },
         Some(("pause", matches)) => Ok(CliCommand::ThreadPause {
             id: parse_string("id", matches)?,
         }),
@@ -179,6 +286,13 @@ fn parse_thread_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
         }),
         Some(("update", matches)) => Ok(CliCommand::ThreadUpdate {
             id: parse_string("id", matches)?,
+            fee_model: parse_fee_model(matches).ok(),
+            fee_token_account: if matches.get_flag("lamport_fees") {
+                Some(None)
+            } else {
+                parse_pubkey("fee_token_account", matches).ok().map(Some)
+            },
+            pool_id: parse_u64("pool", matches).ok(),
             rate_limit: parse_u64("rate_limit", matches).ok(),
             schedule: parse_string("schedule", matches).ok(),
         }),
@@ -191,7 +305,9 @@ fn parse_thread_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
 fn parse_registry_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
         Some(("get", _)) => Ok(CliCommand::RegistryGet {}),
-        Some(("reset", _)) => Ok(CliCommand::RegistryReset {}),
+        Some(("reset", matches)) => Ok(CliCommand::RegistryReset {
+            output_format: parse_string("output", matches).ok(),
+        }),
         Some(("unlock", _)) => Ok(CliCommand::RegistryUnlock {}),
         _ => Err(CliError::CommandNotRecognized(
             matches.subcommand().unwrap().0.into(),
@@ -201,15 +317,53 @@ fn parse_registry_command(matches: &ArgMatches) -> Result<CliCommand, CliError>
 
 fn parse_worker_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
+        Some(("claim", matches)) => Ok(CliCommand::WorkerClaim {
+            id: parse_u64("id", matches)?,
+        }),
+        Some(("close", matches)) => Ok(CliCommand::WorkerClose {
+            id: parse_u64("id", matches)?,
+        }),
         Some(("create", matches)) => Ok(CliCommand::WorkerCreate {
             signatory: parse_keypair_file("signatory_keypair", matches)?,
         }),
+        Some(("deactivate", matches)) => Ok(CliCommand::WorkerDeactivate {
+            id: parse_u64("id", matches)?,
+        }),
         Some(("get", matches)) => Ok(CliCommand::WorkerGet {
             id: parse_u64("id", matches)?,
         }),
+        Some(("list", _)) => Ok(CliCommand::WorkerList),
+        Some(("migrate", matches)) => Ok(CliCommand::WorkerMigrate {
+            id: parse_u64("id", matches)?,
+        }),
+        Some(("slash", matches)) => Ok(CliCommand::WorkerSlash {
+            id: parse_u64("id", matches)?,
+            amount: parse_u64("amount", matches)?,
+        }),
+        Some(("stake", matches)) => Ok(CliCommand::WorkerStake {
+            id: parse_u64("id", matches)?,
+            amount: parse_u64("amount", matches)?,
+        }),
+        Some(("stats", matches)) => Ok(CliCommand::WorkerStats {
+            id: parse_u64("id", matches)?,
+        }),
+        Some(("unbond", matches)) => Ok(CliCommand::WorkerUnbond {
+            id: parse_u64("id", matches)?,
+            amount: parse_u64("amount", matches)?,
+        }),
+        Some(("withdraw", matches)) => Ok(CliCommand::WorkerWithdraw {
+            id: parse_u64("id", matches)?,
+        }),
         Some(("update", matches)) => Ok(CliCommand::WorkerUpdate {
             id: parse_u64("id", matches)?,
             commission_rate: parse_u64("commission_rate", matches).ok(),
+            metadata: WorkerMetadataArgs {
+                contact: parse_string("contact", matches).ok(),
+                name: parse_string("name", matches).ok(),
+                plugin_version: parse_string("plugin_version", matches).ok(),
+                region: parse_string("region", matches).ok(),
+                website: parse_string("website", matches).ok(),
+            },
             signatory: parse_keypair_file("signatory_keypair", matches).ok(),
         }),
         _ => Err(CliError::CommandNotRecognized(
@@ -232,6 +386,10 @@ fn parse_trigger(matches: &ArgMatches) -> Result<Trigger, CliError> {
             schedule: parse_string("cron", matches)?,
             skippable: true,
         });
+    } else if matches.contains_id("every_epoch") {
+        return Ok(Trigger::EveryEpoch {
+            offset_slots: parse_u64("every_epoch", matches)?,
+        });
     } else if matches.contains_id("now") {
         return Ok(Trigger::Now);
     }
@@ -239,6 +397,19 @@ fn parse_trigger(matches: &ArgMatches) -> Result<Trigger, CliError> {
     Err(CliError::BadParameter("trigger".into()))
 }
 
+fn parse_fee_model(matches: &ArgMatches) -> Result<FeeModel, CliError> {
+    match parse_string("fee_model", matches)?.as_str() {
+        "flat" => Ok(FeeModel::Flat),
+        "compute" => Ok(FeeModel::ComputeUnits {
+            lamports_per_1k_cu: parse_u64("lamports_per_1k_cu", matches)?,
+        }),
+        "flat-plus-compute" => Ok(FeeModel::FlatPlusComputeUnits {
+            lamports_per_1k_cu: parse_u64("lamports_per_1k_cu", matches)?,
+        }),
+        _ => Err(CliError::BadParameter("fee_model".into())),
+    }
+}
+
 fn parse_instruction_file(
     arg: &str,
     matches: &ArgMatches,
@@ -1,15 +1,14 @@
 use anchor_lang::{
     solana_program::{
-        hash::Hash,
         instruction::Instruction,
         pubkey::Pubkey,
+        system_program,
     },
     InstructionData, ToAccountMetas
 };
-use antegen_network_program::state::{Config, ConfigSettings};
-use solana_sdk::{message::Message, transaction::Transaction};
+use antegen_network_program::state::{Config, ConfigSettings, ProposalAction};
 
-use crate::{client::Client, errors::CliError};
+use crate::{client::Client, errors::CliError, processor::proposal};
 
 pub fn _get(client: &Client) -> Result<Config, CliError> {
     let config = client
@@ -25,11 +24,42 @@ pub fn get(client: &Client) -> Result<(), CliError> {
     Ok(())
 }
 
+pub fn migrate(client: &Client) -> Result<(), CliError> {
+    let ix = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::ConfigMigrate {
+            config: Config::pubkey(),
+            payer: client.payer_pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::ConfigMigrate {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client)
+}
+
 pub fn set(
     client: &Client,
     admin: Option<Pubkey>,
+    batch_size: Option<u64>,
+    epoch_length: Option<u64>,
+    epoch_summaries: Option<bool>,
     epoch_thread: Option<Pubkey>,
+    fee_mint: Option<Pubkey>,
+    fee_price_feed: Option<Pubkey>,
+    hasher_interval: Option<u64>,
     hasher_thread: Option<Pubkey>,
+    heartbeat_timeout: Option<u64>,
+    max_commission_rate: Option<u64>,
+    min_commission_rate: Option<u64>,
+    min_stake: Option<u64>,
+    network_fee_bps: Option<u64>,
+    network_fee_recipient: Option<Pubkey>,
+    snapshot_retention: Option<u64>,
+    thread_funding: Option<u64>,
+    timelock: Option<u64>,
+    unbonding_period: Option<u64>,
     output_format: Option<String>,
 ) -> Result<(), CliError> {
     // Get the current config.
@@ -40,43 +70,42 @@ pub fn set(
     // Build new config settings
     let settings: ConfigSettings = ConfigSettings {
         admin: admin.unwrap_or(config.admin),
+        batch_size: batch_size.unwrap_or(config.batch_size),
+        epoch_length: epoch_length.unwrap_or(config.epoch_length),
+        epoch_summaries: epoch_summaries.unwrap_or(config.epoch_summaries),
         epoch_thread: epoch_thread.unwrap_or(config.epoch_thread),
-        hasher_thread: hasher_thread.unwrap_or(config.hasher_thread)
+        fee_mint: fee_mint.or(config.fee_mint),
+        fee_price_feed: fee_price_feed.or(config.fee_price_feed),
+        hasher_interval: hasher_interval.unwrap_or(config.hasher_interval),
+        hasher_thread: hasher_thread.unwrap_or(config.hasher_thread),
+        heartbeat_timeout: heartbeat_timeout.unwrap_or(config.heartbeat_timeout),
+        max_commission_rate: max_commission_rate.unwrap_or(config.max_commission_rate),
+        min_commission_rate: min_commission_rate.unwrap_or(config.min_commission_rate),
+        min_stake: min_stake.unwrap_or(config.min_stake),
+        network_fee_bps: network_fee_bps.unwrap_or(config.network_fee_bps),
+        network_fee_recipient: network_fee_recipient.unwrap_or(config.network_fee_recipient),
+        snapshot_retention: snapshot_retention.unwrap_or(config.snapshot_retention),
+        thread_funding: thread_funding.unwrap_or(config.thread_funding),
+        timelock: timelock.unwrap_or(config.timelock),
+        unbonding_period: unbonding_period.unwrap_or(config.unbonding_period),
     };
 
     // Create instruction
     let ix: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::ConfigUpdate {
-            admin: settings.admin,
+            admin: config.admin,
             config: Config::pubkey(),
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::ConfigUpdate {
             settings: settings.clone()
         }.data(),
     };
-    
-    // Check if base58 output is requested
-    if let Some(format) = output_format {
-        if format == "base58" {
-            // Create unsigned transaction
-            let blockhash: Hash = client.get_latest_blockhash().unwrap();
-            let message: Message = Message::new(&[ix], Some(&settings.admin));
-            let mut tx: Transaction = Transaction::new_unsigned(message);
-            tx.message.recent_blockhash = blockhash;
-            
-            // Serialize and base58 encode the transaction
-            let serialized_tx: Vec<u8> = bincode::serialize(&tx).unwrap();
-            let base58_tx = bs58::encode(serialized_tx).into_string();
-            
-            // Print the base58 encoded transaction
-            println!("{}", base58_tx);
-            return Ok(());
-        }
-    }
+    let ix = proposal::admin_instruction(&config, ProposalAction::ConfigUpdate { settings }, ix);
 
-    // Default behavior: submit tx
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
-    get(client)?;
+    // Submit the tx, or export it if base58 output is requested
+    if proposal::send_or_export(client, &[ix], config.admin, output_format)? {
+        get(client)?;
+    }
     Ok(())
 }
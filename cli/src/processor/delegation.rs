@@ -0,0 +1,101 @@
+use {
+    crate::{client::Client, errors::CliError},
+    anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData, ToAccountMetas,
+    },
+    antegen_network_program::state::{Delegation, Registry, Worker},
+    solana_sdk::pubkey::Pubkey,
+};
+
+pub fn get(client: &Client, worker_id: u64, id: u64) -> Result<(), CliError> {
+    let delegation_pubkey = Delegation::pubkey(Worker::pubkey(worker_id), id);
+    let delegation = client
+        .get::<Delegation>(&delegation_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(delegation_pubkey.to_string()))?;
+    println!("Address: {}\n{:#?}", delegation_pubkey, delegation);
+    Ok(())
+}
+
+pub fn create(client: &Client, worker_id: u64) -> Result<(), CliError> {
+    // Get the worker's next delegation id.
+    let worker_pubkey: Pubkey = Worker::pubkey(worker_id);
+    let worker = client
+        .get::<Worker>(&worker_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(worker_pubkey.to_string()))?;
+
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::DelegationCreate {
+            authority: client.payer_pubkey(),
+            delegation: Delegation::pubkey(worker_pubkey, worker.total_delegations),
+            registry: Registry::pubkey(),
+            system_program: system_program::ID,
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::DelegationCreate {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, worker_id, worker.total_delegations)?;
+    Ok(())
+}
+
+pub fn deposit(client: &Client, worker_id: u64, id: u64, amount: u64) -> Result<(), CliError> {
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::DelegationDeposit {
+            authority: client.payer_pubkey(),
+            delegation: Delegation::pubkey(Worker::pubkey(worker_id), id),
+            registry: Registry::pubkey(),
+            system_program: system_program::ID,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::DelegationDeposit { amount }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, worker_id, id)?;
+    Ok(())
+}
+
+pub fn unbond(client: &Client, worker_id: u64, id: u64, amount: u64) -> Result<(), CliError> {
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::DelegationUnbond {
+            authority: client.payer_pubkey(),
+            delegation: Delegation::pubkey(Worker::pubkey(worker_id), id),
+            registry: Registry::pubkey(),
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::DelegationUnbond { amount }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, worker_id, id)?;
+    Ok(())
+}
+
+pub fn withdraw(client: &Client, worker_id: u64, id: u64) -> Result<(), CliError> {
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::DelegationWithdraw {
+            authority: client.payer_pubkey(),
+            delegation: Delegation::pubkey(Worker::pubkey(worker_id), id),
+            registry: Registry::pubkey(),
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::DelegationWithdraw {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, worker_id, id)?;
+    Ok(())
+}
+
+pub fn claim(client: &Client, worker_id: u64, id: u64) -> Result<(), CliError> {
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::DelegationClaim {
+            authority: client.payer_pubkey(),
+            delegation: Delegation::pubkey(Worker::pubkey(worker_id), id),
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::DelegationClaim {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, worker_id, id)?;
+    Ok(())
+}
@@ -70,7 +70,7 @@ pub fn start(
 
     // Initialize Antegen
     super::network::initialize(client)?;
-    super::network::create_threads(client, LAMPORTS_PER_SOL)?;
+    super::network::create_threads(client)?;
     register_worker(client, config)?;
 
     Ok(())
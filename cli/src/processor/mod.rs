@@ -1,8 +1,10 @@
 mod config;
 mod crontab;
+mod delegation;
 mod network;
 mod localnet;
 mod pool;
+mod proposal;
 mod registry;
 // mod snapshot;
 mod thread;
@@ -44,15 +46,68 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
     // Process the command
     match command {
         CliCommand::Crontab { schedule } => crontab::get(&client, schedule),
+        CliCommand::DelegationClaim { worker_id, id } => delegation::claim(&client, worker_id, id),
+        CliCommand::DelegationCreate { worker_id } => delegation::create(&client, worker_id),
+        CliCommand::DelegationDeposit { worker_id, id, amount } => delegation::deposit(&client, worker_id, id, amount),
+        CliCommand::DelegationGet { worker_id, id } => delegation::get(&client, worker_id, id),
+        CliCommand::DelegationUnbond { worker_id, id, amount } => delegation::unbond(&client, worker_id, id, amount),
+        CliCommand::DelegationWithdraw { worker_id, id } => delegation::withdraw(&client, worker_id, id),
         CliCommand::NetworkInitialize {} => network::initialize(&client),
-        CliCommand::NetworkThreadCreate { amount } => network::create_threads(&client, amount),
+        CliCommand::NetworkThreadCreate => network::create_threads(&client),
+        CliCommand::NetworkThreadSync { output_format } => network::sync_threads(&client, output_format),
         CliCommand::NetworkConfigGet => config::get(&client),
+        CliCommand::NetworkConfigMigrate => config::migrate(&client),
+        CliCommand::NetworkEpochMigrate { id } => network::migrate_snapshot(&client, id),
+        CliCommand::NetworkEpochStatus => network::epoch_status(&client),
+        CliCommand::NetworkEpochSummary { epoch } => network::epoch_summary(&client, epoch),
+        CliCommand::NetworkProposalCancel { id, output_format } => proposal::cancel(&client, id, output_format),
+        CliCommand::NetworkProposalExecute { id } => proposal::execute(&client, id),
+        CliCommand::NetworkProposalGet { id } => proposal::get(&client, id),
+        CliCommand::NetworkProposalList => proposal::list(&client),
         CliCommand::NetworkConfigSet {
             admin,
+            batch_size,
+            epoch_length,
+            epoch_summaries,
             epoch_thread,
+            fee_mint,
+            fee_price_feed,
+            hasher_interval,
             hasher_thread,
+            heartbeat_timeout,
+            max_commission_rate,
+            min_commission_rate,
+            min_stake,
+            network_fee_bps,
+            network_fee_recipient,
+            snapshot_retention,
+            thread_funding,
+            timelock,
+            unbonding_period,
             output_format
-        } => config::set(&client, admin, epoch_thread, hasher_thread, output_format),
+        } => config::set(
+            &client,
+            admin,
+            batch_size,
+            epoch_length,
+            epoch_summaries,
+            epoch_thread,
+            fee_mint,
+            fee_price_feed,
+            hasher_interval,
+            hasher_thread,
+            heartbeat_timeout,
+            max_commission_rate,
+            min_commission_rate,
+            min_stake,
+            network_fee_bps,
+            network_fee_recipient,
+            snapshot_retention,
+            thread_funding,
+            timelock,
+            unbonding_period,
+            output_format,
+        ),
         CliCommand::Localnet {
             clone_addresses,
             program_infos,
@@ -74,9 +129,19 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
         ),
         CliCommand::PoolGet { id } => pool::get(&client, id),
         CliCommand::PoolList {} => pool::list(&client),
-        CliCommand::PoolUpdate { id, size } => pool::update(&client, id, size),
+        CliCommand::PoolMigrate { id } => pool::migrate(&client, id),
+        CliCommand::PoolUpdate {
+            id,
+            rotation_period,
+            size,
+            output_format,
+        } => pool::update(&client, id, rotation_period, size, output_format),
         CliCommand::PoolRotate { id} => pool::rotate(&client, id),
         CliCommand::ThreadCrateInfo {} => thread::crate_info(&client),
+        CliCommand::ThreadCheck { id, address } => {
+            let pubkey = parse_pubkey_from_id_or_address(client.payer_pubkey(), id, address)?;
+            thread::check(&client, pubkey)
+        }
         CliCommand::ThreadCreate {
             id,
             kickoff_instruction,
@@ -92,6 +157,19 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
             let pubkey = parse_pubkey_from_id_or_address(client.payer_pubkey(), id, address)?;
             thread::delete(&client, pubkey)
         },
+        CliCommand::ThreadInstructionInsert {
+            id,
+            index,
+            instruction,
+        } => thread::instruction_insert(&client, id, index, instruction),
+        CliCommand::ThreadInstructionMove { id, from, to } => {
+            thread::instruction_move(&client, id, from, to)
+        }
+        CliCommand::ThreadInstructionReplace {
+            id,
+            index,
+            instruction,
+        } => thread::instruction_replace(&client, id, index, instruction),
         CliCommand::ThreadPause { id } => thread::pause(&client, id),
         CliCommand::ThreadResume { id } => thread::resume(&client, id),
         CliCommand::ThreadReset { id } => thread::reset(&client, id),
@@ -101,15 +179,36 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
         }
         CliCommand::ThreadUpdate {
             id,
+            fee_model,
+            fee_token_account,
+            pool_id,
+            rate_limit,
+            schedule,
+        } => thread::update(
+            &client,
+            id,
+            fee_model,
+            fee_token_account,
+            pool_id,
             rate_limit,
             schedule,
-        } => thread::update(&client, id, rate_limit, schedule),
+        ),
         CliCommand::RegistryGet => registry::get(&client),
-        CliCommand::RegistryReset => registry::reset(&client),
+        CliCommand::RegistryReset { output_format } => registry::reset(&client, output_format),
         CliCommand::RegistryUnlock => registry::unlock(&client),
+        CliCommand::WorkerClaim { id } => worker::claim(&client, id),
+        CliCommand::WorkerClose { id } => worker::close(&client, id),
+        CliCommand::WorkerDeactivate { id } => worker::deactivate(&client, id),
         CliCommand::WorkerCreate { signatory } => worker::create(&client, signatory, false),
         CliCommand::WorkerGet { id} => worker::get(&client, id),
-        CliCommand::WorkerUpdate { id, commission_rate, signatory } => worker::update(&client, id, commission_rate, signatory),
+        CliCommand::WorkerList => worker::list(&client),
+        CliCommand::WorkerMigrate { id } => worker::migrate(&client, id),
+        CliCommand::WorkerSlash { id, amount } => worker::slash(&client, id, amount),
+        CliCommand::WorkerStake { id, amount } => worker::stake(&client, id, amount),
+        CliCommand::WorkerStats { id } => worker::stats(&client, id),
+        CliCommand::WorkerUnbond { id, amount } => worker::unbond(&client, id, amount),
+        CliCommand::WorkerWithdraw { id } => worker::withdraw(&client, id),
+        CliCommand::WorkerUpdate { id, commission_rate, metadata, signatory } => worker::update(&client, id, commission_rate, metadata, signatory),
     }
 }
 
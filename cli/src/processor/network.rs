@@ -3,29 +3,41 @@ use {
         client::Client,
         errors::CliError,
         print::print_style,
+        processor::proposal,
         print_status
     }, anchor_lang::{
         prelude::Pubkey,
         solana_program::{
-            instruction::Instruction,
+            hash::Hash,
+            instruction::{AccountMeta, Instruction},
+            system_instruction,
             system_program,
         },
+        AnchorDeserialize,
         InstructionData,
         ToAccountMetas,
     }, antegen_network_program::{
         state::{
             Config,
+            EpochJob,
+            EpochSummary,
+            LegacySnapshot,
             Pool,
             Registry,
-            Snapshot
+            Snapshot,
+            SnapshotFrame,
         },
         ANTEGEN_SQUADS, EPOCH_THREAD_ID, HASHER_THREAD_ID
     },
-    antegen_thread_program::state::{Thread, Trigger},
-    antegen_utils::explorer::Explorer,
-    anyhow::Context
+    antegen_thread_program::state::{Thread, ThreadSettings, Trigger},
+    antegen_utils::{explorer::Explorer, thread::PAYER_PUBKEY},
+    anyhow::Context,
+    std::mem::size_of,
 };
 
+/// The number of frames migrated per transaction.
+const SNAPSHOT_MIGRATE_BATCH_SIZE: usize = 8;
+
 pub fn initialize(client: &Client) -> Result<(), CliError> {
     let payer: Pubkey = client.payer_pubkey();
     let registry: Pubkey = Registry::pubkey();
@@ -58,18 +70,13 @@ pub fn initialize(client: &Client) -> Result<(), CliError> {
     Ok(())
 }
 
-pub fn create_threads(client: &Client, amount: u64) -> Result<(), CliError> {
-    #[cfg(feature = "mainnet")]
-    let cron_epoch: &str = "@hourly";
-
-    #[cfg(not(feature = "mainnet"))]
-    let cron_epoch: &str = "0 * * * * * *";
-
-    #[cfg(feature = "mainnet")]
-    let cron_hasher: &str = "0 */15 * * * * *";
-
-    #[cfg(not(feature = "mainnet"))]
-    let cron_hasher: &str = "*/15 * * * * * *";
+pub fn create_threads(client: &Client) -> Result<(), CliError> {
+    // The threads' schedules and funding are network parameters.
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+    let cron_epoch: String = cron_schedule(config.epoch_length)?;
+    let cron_hasher: String = cron_schedule(config.hasher_interval)?;
 
     let explorer: Explorer = Explorer::from(client.client.url());
     let payer: Pubkey = client.payer_pubkey();
@@ -85,36 +92,54 @@ pub fn create_threads(client: &Client, amount: u64) -> Result<(), CliError> {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::DistributeFeesJob {
             config: Config::pubkey(),
+            epoch_job: EpochJob::pubkey(),
+            payer: PAYER_PUBKEY,
             registry: Registry::pubkey(),
+            system_program: system_program::ID,
             thread: epoch_thread_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::DistributeFeesJob {}.data(),
     };
 
     let ix_a2: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::EvictWorkersJob {
+            config: Config::pubkey(),
+            epoch_job: EpochJob::pubkey(),
+            pool: Pool::pubkey(0),
+            registry: Registry::pubkey(),
+            thread: epoch_thread_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::EvictWorkersJob {}.data(),
+    };
+
+    let ix_a3: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::TakeSnapshotJob {
             config: Config::pubkey(),
+            epoch_job: EpochJob::pubkey(),
             registry: Registry::pubkey(),
             thread: epoch_thread_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::TakeSnapshotJob {}.data(),
     };
 
-    let ix_a3: Instruction = Instruction {
+    let ix_a4: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::EpochCutover {
             config: Config::pubkey(),
+            epoch_job: EpochJob::pubkey(),
             registry: Registry::pubkey(),
             thread: epoch_thread_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::IncrementEpoch {}.data(),
     };
 
-    let ix_a4: Instruction = Instruction {
+    let ix_a5: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::DeleteSnapshotJob {
             config: Config::pubkey(),
+            epoch_job: EpochJob::pubkey(),
             registry: Registry::pubkey(),
             thread: epoch_thread_pubkey,
         }.to_account_metas(Some(false)),
@@ -130,16 +155,17 @@ pub fn create_threads(client: &Client, amount: u64) -> Result<(), CliError> {
             thread: epoch_thread_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_thread_program::instruction::ThreadCreate {
-            amount,
+            amount: config.thread_funding,
             id: EPOCH_THREAD_ID.into(),
             instructions: vec![
                 ix_a1.into(),
                 ix_a2.into(),
                 ix_a3.into(),
                 ix_a4.into(),
+                ix_a5.into(),
             ],
             trigger: Trigger::Cron {
-                schedule: cron_epoch.into(),
+                schedule: cron_epoch,
                 skippable: true,
             },
         }.data(),
@@ -166,13 +192,13 @@ pub fn create_threads(client: &Client, amount: u64) -> Result<(), CliError> {
             thread: hasher_thread_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_thread_program::instruction::ThreadCreate {
-            amount,
+            amount: config.thread_funding,
             id: HASHER_THREAD_ID.into(),
             instructions: vec![
                 ix_b1.into(),
             ],
             trigger: Trigger::Cron {
-                schedule: cron_hasher.into(),
+                schedule: cron_hasher,
                 skippable: true,
             },
         }.data(),
@@ -193,3 +219,173 @@ pub fn create_threads(client: &Client, amount: u64) -> Result<(), CliError> {
     print_status!("Admin    👔", "{}", explorer.account(admin.to_string()));
     Ok(())
 }
+
+pub fn sync_threads(client: &Client, output_format: Option<String>) -> Result<(), CliError> {
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+    let explorer: Explorer = Explorer::from(client.client.url());
+    let payer: Pubkey = client.payer_pubkey();
+
+    for (thread_pubkey, interval) in [
+        (config.epoch_thread, config.epoch_length),
+        (config.hasher_thread, config.hasher_interval),
+    ] {
+        let thread = client
+            .get::<Thread>(&thread_pubkey)
+            .map_err(|_err| CliError::AccountNotFound(thread_pubkey.to_string()))?;
+
+        // Top up the thread's balance, above its rent, to the configured funding. Anyone may fund a thread.
+        let thread_account = client
+            .get_account(&thread_pubkey)
+            .map_err(|_err| CliError::AccountNotFound(thread_pubkey.to_string()))?;
+        let rent_balance: u64 = client
+            .get_minimum_balance_for_rent_exemption(thread_account.data.len())
+            .map_err(|err| CliError::BadClient(err.to_string()))?;
+        let funded_balance: u64 = thread_account.lamports.saturating_sub(rent_balance);
+        if funded_balance.lt(&config.thread_funding) {
+            let ix = system_instruction::transfer(
+                &payer,
+                &thread_pubkey,
+                config.thread_funding - funded_balance,
+            );
+            client
+                .send_and_confirm(&[ix], &[client.payer()])
+                .context(format!("Failed to fund thread: {}", thread.name))?;
+        }
+
+        // Update the trigger only if the schedule changed, since doing so resets the thread's exec context.
+        // Only the thread's authority (e.g. the multisig on mainnet) may update it, so the tx may be exported instead.
+        let schedule: String = cron_schedule(interval)?;
+        let is_synced = matches!(&thread.trigger, Trigger::Cron { schedule: current, .. } if current.eq(&schedule));
+        if !is_synced {
+            let ix = Instruction {
+                program_id: antegen_thread_program::ID,
+                accounts: antegen_thread_program::accounts::ThreadUpdate {
+                    authority: thread.authority,
+                    config: None,
+                    pool: None,
+                    system_program: system_program::ID,
+                    thread: thread_pubkey,
+                }.to_account_metas(Some(false)),
+                data: antegen_thread_program::instruction::ThreadUpdate {
+                    settings: ThreadSettings {
+                        fee: None,
+                        fee_model: None,
+                        fee_token_account: None,
+                        instructions: None,
+                        name: None,
+                        pool_id: None,
+                        rate_limit: None,
+                        trigger: Some(Trigger::Cron {
+                            schedule,
+                            skippable: true,
+                        }),
+                    },
+                }.data(),
+            };
+            if !proposal::send_or_export(client, &[ix], thread.authority, output_format.clone())? {
+                continue;
+            }
+        }
+        print_status!("Synced   🧵", "{}", explorer.account(thread_pubkey.to_string()));
+    }
+    Ok(())
+}
+
+/// The cron schedule of a network thread running every given number of seconds.
+fn cron_schedule(interval: u64) -> Result<String, CliError> {
+    Config::cron_schedule(interval).ok_or(CliError::BadParameter(format!(
+        "{} seconds does not evenly divide a minute, an hour or a day",
+        interval
+    )))
+}
+
+pub fn epoch_status(client: &Client) -> Result<(), CliError> {
+    let registry = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Registry::pubkey().to_string()))?;
+    println!("Current epoch: {}", registry.current_epoch);
+    println!("Registry locked: {}", registry.locked);
+
+    // The epoch job account is created the first time the epoch thread runs.
+    match client.get::<EpochJob>(&EpochJob::pubkey()) {
+        Err(_err) => println!("Epoch jobs have not run yet"),
+        Ok(epoch_job) => {
+            println!("Address: {}", EpochJob::pubkey());
+            println!("{:#?}", epoch_job);
+        }
+    }
+    Ok(())
+}
+
+pub fn migrate_snapshot(client: &Client, id: Option<u64>) -> Result<(), CliError> {
+    let registry = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Registry::pubkey().to_string()))?;
+    let id = id.unwrap_or(registry.current_epoch);
+
+    // The legacy fields lead both layouts, so the snapshot's frame count can be read either way.
+    let snapshot_pubkey = Snapshot::pubkey(id);
+    let snapshot_data = client
+        .get_account_data(&snapshot_pubkey)
+        .map_err(|_err| CliError::AccountNotFound(snapshot_pubkey.to_string()))?;
+    let legacy_snapshot = LegacySnapshot::deserialize(&mut &snapshot_data[8..])
+        .map_err(|_err| CliError::AccountDataNotParsable(snapshot_pubkey.to_string()))?;
+    let is_snapshot_legacy = snapshot_data.len() < 8 + size_of::<Snapshot>();
+
+    // Find the frames still on their legacy layout.
+    let legacy_frame_pubkeys: Vec<Pubkey> = (0..legacy_snapshot.total_frames)
+        .map(|frame_id| SnapshotFrame::pubkey(snapshot_pubkey, frame_id))
+        .filter(|frame_pubkey| {
+            client
+                .get_account_data(frame_pubkey)
+                .is_ok_and(|data| data.len() < 8 + size_of::<SnapshotFrame>())
+        })
+        .collect();
+    if !is_snapshot_legacy && legacy_frame_pubkeys.is_empty() {
+        println!("Snapshot {} is already migrated", snapshot_pubkey);
+        return Ok(());
+    }
+
+    // Migrate the snapshot with the first batch of frames, then the remaining batches.
+    let mut batches: Vec<&[Pubkey]> = legacy_frame_pubkeys.chunks(SNAPSHOT_MIGRATE_BATCH_SIZE).collect();
+    if batches.is_empty() {
+        batches.push(&[]);
+    }
+    for frame_pubkeys in batches {
+        let mut ix = Instruction {
+            program_id: antegen_network_program::ID,
+            accounts: antegen_network_program::accounts::SnapshotMigrate {
+                payer: client.payer_pubkey(),
+                snapshot: snapshot_pubkey,
+                system_program: system_program::ID,
+            }.to_account_metas(Some(false)),
+            data: antegen_network_program::instruction::SnapshotMigrate { id }.data(),
+        };
+        for frame_pubkey in frame_pubkeys {
+            ix.accounts.push(AccountMeta::new(*frame_pubkey, false));
+        }
+        client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    }
+    println!(
+        "Migrated snapshot {} and {} frames",
+        snapshot_pubkey,
+        legacy_frame_pubkeys.len()
+    );
+    Ok(())
+}
+
+pub fn epoch_summary(client: &Client, epoch: u64) -> Result<(), CliError> {
+    // Summaries are only recorded while enabled in the network config.
+    let epoch_summary_pubkey = EpochSummary::pubkey(epoch);
+    let epoch_summary = client
+        .get::<EpochSummary>(&epoch_summary_pubkey)
+        .map_err(|_err| CliError::AccountNotFound(epoch_summary_pubkey.to_string()))?;
+    println!("Address: {}", epoch_summary_pubkey);
+    println!("Epoch: {}", epoch_summary.epoch);
+    println!("Total fees: {}", epoch_summary.total_fees);
+    println!("Total workers: {}", epoch_summary.total_workers);
+    println!("Payouts root: {}", Hash::new_from_array(epoch_summary.payouts_root));
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use {
-    crate::{client::Client, errors::CliError},
+    crate::{client::Client, errors::CliError, processor::proposal},
     anchor_lang::{
         solana_program::{instruction::Instruction, system_program},
         InstructionData,
         ToAccountMetas,
     },
-    antegen_network_program::state::{Config, Pool, PoolSettings, Registry, Worker},
+    antegen_network_program::state::{
+        Config, Pool, PoolSettings, ProposalAction, Registry, Worker, WorkerStake,
+    },
 };
 
 pub fn get(client: &Client, id: u64) -> Result<(), CliError> {
@@ -34,21 +36,53 @@ pub fn list(client: &Client) -> Result<(), CliError> {
     Ok(())
 }
 
-pub fn update(client: &Client, id: u64, size: u64) -> Result<(), CliError> {
+pub fn migrate(client: &Client, id: u64) -> Result<(), CliError> {
+    let ix = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::PoolMigrate {
+            payer: client.payer_pubkey(),
+            pool: Pool::pubkey(id),
+            system_program: system_program::ID,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::PoolMigrate { id }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn update(
+    client: &Client,
+    id: u64,
+    rotation_period: Option<u64>,
+    size: Option<u64>,
+    output_format: Option<String>,
+) -> Result<(), CliError> {
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
     let pool_pubkey = Pool::pubkey(id);
-    let settings = PoolSettings { size };
+    let pool = client
+        .get::<Pool>(&pool_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(pool_pubkey.to_string()))?;
+    let settings = PoolSettings {
+        rotation_period: rotation_period.unwrap_or(pool.rotation_period),
+        size: size.unwrap_or(pool.size),
+    };
     let ix = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::PoolUpdate {
-            admin: client.payer_pubkey(),
+            admin: config.admin,
             config: Config::pubkey(),
             pool: pool_pubkey,
             system_program: system_program::ID,
         }.to_account_metas(Some(false)),
-        data: antegen_network_program::instruction::PoolUpdate { settings }.data(),
+        data: antegen_network_program::instruction::PoolUpdate { settings: settings.clone() }.data(),
     };
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
-    get(client, id)?;
+    let ix = proposal::admin_instruction(&config, ProposalAction::PoolUpdate { pool_id: id, settings }, ix);
+    if proposal::send_or_export(client, &[ix], config.admin, output_format)? {
+        get(client, id)?;
+    }
     Ok(())
 }
 
@@ -61,7 +95,10 @@ pub fn rotate(client: &Client, id: u64) -> Result<(), CliError> {
         accounts: antegen_network_program::accounts::PoolRotate {
             config: Config::pubkey(),
             pool: pool_pubkey,
+            registry: Registry::pubkey(),
             signatory: client.payer_pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            system_program: system_program::ID,
             worker: worker_pubkey,
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::PoolRotate {}.data(),
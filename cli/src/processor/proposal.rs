@@ -0,0 +1,150 @@
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program,
+    },
+    InstructionData, ToAccountMetas,
+};
+use antegen_network_program::state::{Config, Pool, Proposal, ProposalAction, Registry, Snapshot, WorkerStake};
+
+use crate::{client::Client, errors::CliError};
+
+pub fn get(client: &Client, id: u64) -> Result<(), CliError> {
+    let proposal_pubkey = Proposal::pubkey(id);
+    let proposal = client
+        .get::<Proposal>(&proposal_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(proposal_pubkey.to_string()))?;
+    println!("Address: {}\n{:#?}", proposal_pubkey, proposal);
+    Ok(())
+}
+
+pub fn list(client: &Client) -> Result<(), CliError> {
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+
+    // Executed and cancelled proposals are closed, so only pending ones are found.
+    for id in 0..config.total_proposals {
+        let proposal_pubkey = Proposal::pubkey(id);
+        if let Ok(proposal) = client.get::<Proposal>(&proposal_pubkey) {
+            println!("Address: {}\n{:#?}", proposal_pubkey, proposal);
+        }
+    }
+    Ok(())
+}
+
+pub fn cancel(client: &Client, id: u64, output_format: Option<String>) -> Result<(), CliError> {
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+    let proposal_pubkey = Proposal::pubkey(id);
+    let proposal = client
+        .get::<Proposal>(&proposal_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(proposal_pubkey.to_string()))?;
+    let ix = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::ProposalCancel {
+            admin: config.admin,
+            config: Config::pubkey(),
+            proposal: proposal_pubkey,
+            proposer: proposal.proposer,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::ProposalCancel {}.data(),
+    };
+    if send_or_export(client, &[ix], config.admin, output_format)? {
+        println!("Cancelled proposal {}", proposal_pubkey);
+    }
+    Ok(())
+}
+
+pub fn execute(client: &Client, id: u64) -> Result<(), CliError> {
+    let proposal_pubkey = Proposal::pubkey(id);
+    let proposal = client
+        .get::<Proposal>(&proposal_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(proposal_pubkey.to_string()))?;
+
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+
+    // Pass the accounts the proposed action touches.
+    let registry_state = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Registry::pubkey().to_string()))?;
+    let (pool, registry, snapshot) = match &proposal.action {
+        ProposalAction::ConfigUpdate { .. } => (None, None, None),
+        ProposalAction::PoolUpdate { pool_id, .. } => (Some(Pool::pubkey(*pool_id)), None, None),
+        ProposalAction::RegistryReset => (None, Some(Registry::pubkey()), Some(Snapshot::pubkey(0))),
+        ProposalAction::RegistryUnlock => (None, Some(Registry::pubkey()), None),
+        ProposalAction::WorkerSlash { .. } => (None, Some(Registry::pubkey()), None),
+    };
+    let (network_fee, stake, worker) = match &proposal.action {
+        ProposalAction::WorkerSlash { worker, .. } => (
+            Some(config.network_fee_recipient),
+            Some(WorkerStake::pubkey(*worker)),
+            Some(*worker),
+        ),
+        _ => (None, None, None),
+    };
+    let mut ix = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::ProposalExecute {
+            config: Config::pubkey(),
+            network_fee,
+            payer: client.payer_pubkey(),
+            pool,
+            proposal: proposal_pubkey,
+            proposer: proposal.proposer,
+            registry,
+            snapshot,
+            stake,
+            system_program: system_program::ID,
+            worker,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::ProposalExecute {}.data(),
+    };
+
+    // A slashed worker whose stake falls below the minimum is evicted from every pool.
+    if let ProposalAction::WorkerSlash { .. } = proposal.action {
+        for pool_id in 0..registry_state.total_pools {
+            ix.accounts.push(AccountMeta::new(Pool::pubkey(pool_id), false));
+        }
+    }
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    println!("Executed proposal {}", proposal_pubkey);
+    Ok(())
+}
+
+/// Wraps an admin instruction in a proposal when the network's timelock requires one.
+pub fn admin_instruction(config: &Config, action: ProposalAction, ix: Instruction) -> Instruction {
+    if config.timelock.eq(&0) {
+        return ix;
+    }
+    Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::ProposalCreate {
+            admin: config.admin,
+            config: Config::pubkey(),
+            proposal: Proposal::pubkey(config.total_proposals),
+            system_program: system_program::ID,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::ProposalCreate { action }.data(),
+    }
+}
+
+/// Submits an admin transaction, or prints it unsigned so the admin (e.g. a multisig) can sign it elsewhere.
+/// Returns whether the transaction was submitted.
+pub fn send_or_export(
+    client: &Client,
+    ixs: &[Instruction],
+    admin: Pubkey,
+    output_format: Option<String>,
+) -> Result<bool, CliError> {
+    if output_format.as_deref() == Some("base58") {
+        println!("{}", client.export_transaction(ixs, &admin).unwrap());
+        return Ok(false);
+    }
+    client.send_and_confirm(ixs, &[client.payer()]).unwrap();
+    Ok(true)
+}
@@ -6,9 +6,9 @@ use anchor_lang::{
     },
     InstructionData, ToAccountMetas
 };
-use antegen_network_program::{state::*, ANTEGEN_SQUADS};
+use antegen_network_program::state::*;
 
-use crate::{client::Client, errors::CliError};
+use crate::{client::Client, errors::CliError, processor::proposal};
 
 pub fn get(client: &Client) -> Result<(), CliError> {
     let registry_pubkey: Pubkey = antegen_network_program::state::Registry::pubkey();
@@ -21,7 +21,11 @@ pub fn get(client: &Client) -> Result<(), CliError> {
         .get::<Snapshot>(&snapshot_pubkey)
         .map_err(|_err| CliError::AccountDataNotParsable(snapshot_pubkey.to_string()))?;
 
-    let network_fee_pubkey: Pubkey = ANTEGEN_SQUADS;
+    let config_pubkey: Pubkey = Config::pubkey();
+    let config: Config = client
+        .get::<Config>(&config_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(config_pubkey.to_string()))?;
+    let network_fee_pubkey: Pubkey = config.network_fee_recipient;
     let fee_data: Vec<u8> = client
         .get_account_data(&network_fee_pubkey)
         .map_err(|_err| CliError::AccountNotFound(network_fee_pubkey.to_string()))?;
@@ -34,17 +38,50 @@ pub fn get(client: &Client) -> Result<(), CliError> {
     println!("{}\n{:#?}", registry_pubkey, registry);
     println!("Balance: {}\n", registry_balance);
     println!("{}\n{:#?}", snapshot_pubkey, snapshot);
+
+    // The current epoch's work is summed from the workers' statistics.
+    let activity = (0..registry.total_workers)
+        .filter_map(|id| client.get::<WorkerStats>(&WorkerStats::pubkey(Worker::pubkey(id))).ok())
+        .map(|stats| stats.activity(registry.current_epoch))
+        .fold(WorkerActivity::default(), |total, activity| WorkerActivity {
+            execs: total.execs.saturating_add(activity.execs),
+            fees: total.fees.saturating_add(activity.fees),
+            kickoffs: total.kickoffs.saturating_add(activity.kickoffs),
+            reimbursements: total.reimbursements.saturating_add(activity.reimbursements),
+        });
+    println!(
+        "\nEpoch {}\nExecs: {}\nKickoffs: {}\nFees: {} lamports",
+        registry.current_epoch, activity.execs, activity.kickoffs, activity.fees
+    );
+
+    // The network's statistics are aggregated by the epoch job once the first epoch closes.
+    let stats_pubkey: Pubkey = RegistryStats::pubkey();
+    if let Ok(stats) = client.get::<RegistryStats>(&stats_pubkey) {
+        println!(
+            "\n{}\nEpoch {}\nExecs: {}\nKickoffs: {}\nFees: {} lamports\n\nTotal\nExecs: {}\nKickoffs: {}\nFees: {} lamports",
+            stats_pubkey,
+            stats.epoch,
+            stats.epoch_execs,
+            stats.epoch_kickoffs,
+            stats.epoch_fees,
+            stats.total_execs,
+            stats.total_kickoffs,
+            stats.total_fees
+        );
+    }
     Ok(())
 }
 
-pub fn reset(client: &Client) -> Result<(), CliError> {
-    let payer: Pubkey = client.payer_pubkey();
+pub fn reset(client: &Client, output_format: Option<String>) -> Result<(), CliError> {
+    let config: Config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
 
     let registry: Pubkey = Registry::pubkey();
     let ix: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::RegistryReset {
-            admin: payer,
+            admin: config.admin,
             config: Config::pubkey(),
             registry,
             snapshot: Snapshot::pubkey(0),
@@ -52,22 +89,29 @@ pub fn reset(client: &Client) -> Result<(), CliError> {
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::RegistryReset {}.data(),
     };
+    let ix = proposal::admin_instruction(&config, ProposalAction::RegistryReset, ix);
 
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
-    get(client)?;
+    if proposal::send_or_export(client, &[ix], config.admin, output_format)? {
+        get(client)?;
+    }
     Ok(())
 }
 
 pub fn unlock(client: &Client) -> Result<(), CliError> {
+    let config: Config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+
     let ix: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::RegistryUnlock {
-            admin: client.payer_pubkey(),
+            admin: config.admin,
             config: Config::pubkey(),
             registry: Registry::pubkey()
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::RegistryUnlock {}.data(),
     };
+    let ix = proposal::admin_instruction(&config, ProposalAction::RegistryUnlock, ix);
     client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
     get(client)?;
     Ok(())
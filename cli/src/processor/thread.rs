@@ -1,10 +1,17 @@
 use anchor_lang::{
-    solana_program::{instruction::Instruction, system_program},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        system_program,
+    },
     AccountDeserialize,
     InstructionData,
     ToAccountMetas
 };
-use antegen_thread_program::state::{SerializableInstruction, Thread, VersionedThread, ThreadSettings, Trigger};
+use antegen_network_program::state::{Config, Pool, Registry};
+use antegen_thread_program::state::{
+    FeeModel, SerializableInstruction, Thread, ThreadCheckResponse, ThreadSettings, Trigger,
+    VersionedThread,
+};
 use antegen_utils::CrateInfo;
 use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
 use crate::{client::Client, errors::CliError};
@@ -22,6 +29,32 @@ pub fn crate_info(client: &Client) -> Result<(), CliError> {
     Ok(())
 }
 
+pub fn check(client: &Client, address: Pubkey) -> Result<(), CliError> {
+    let data = client.get_account_data(&address).unwrap();
+    let thread = VersionedThread::try_deserialize(&mut data.as_slice()).unwrap();
+    let mut ix = Instruction {
+        program_id: antegen_thread_program::ID,
+        accounts: antegen_thread_program::accounts::ThreadCheck {
+            pool: Some(Pool::pubkey(thread.pool_id())),
+            registry: Some(Registry::pubkey()),
+            thread: address,
+        }.to_account_metas(Some(false)),
+        data: antegen_thread_program::instruction::ThreadCheck {}.data(),
+    };
+
+    // If the thread's trigger is account-based, inject the triggering account.
+    match thread.trigger() {
+        Trigger::Account { address, .. } | Trigger::Pyth { price_feed: address, .. } => {
+            ix.accounts.push(AccountMeta::new_readonly(address, false))
+        }
+        _ => {}
+    }
+
+    let response: ThreadCheckResponse = client.get_return_data(ix).unwrap();
+    println!("Address: {}\n{:#?}", address, response);
+    Ok(())
+}
+
 pub fn create(
     client: &Client,
     id: String,
@@ -163,6 +196,69 @@ pub fn get(client: &Client, address: Pubkey) -> Result<(), CliError> {
     Ok(())
 }
 
+pub fn instruction_insert(
+    client: &Client,
+    id: String,
+    index: u64,
+    instruction: SerializableInstruction,
+) -> Result<(), CliError> {
+    let thread_pubkey = Thread::pubkey(client.payer_pubkey(), id.into_bytes());
+    let ix = Instruction {
+        program_id: antegen_thread_program::ID,
+        accounts: antegen_thread_program::accounts::ThreadInstructionInsert {
+            authority: client.payer_pubkey(),
+            system_program: system_program::ID,
+            thread: thread_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_thread_program::instruction::ThreadInstructionInsert {
+            index,
+            instruction,
+        }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, thread_pubkey)?;
+    Ok(())
+}
+
+pub fn instruction_move(client: &Client, id: String, from: u64, to: u64) -> Result<(), CliError> {
+    let thread_pubkey = Thread::pubkey(client.payer_pubkey(), id.into_bytes());
+    let ix = Instruction {
+        program_id: antegen_thread_program::ID,
+        accounts: antegen_thread_program::accounts::ThreadInstructionMove {
+            authority: client.payer_pubkey(),
+            thread: thread_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_thread_program::instruction::ThreadInstructionMove { from, to }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, thread_pubkey)?;
+    Ok(())
+}
+
+pub fn instruction_replace(
+    client: &Client,
+    id: String,
+    index: u64,
+    instruction: SerializableInstruction,
+) -> Result<(), CliError> {
+    let thread_pubkey = Thread::pubkey(client.payer_pubkey(), id.into_bytes());
+    let ix = Instruction {
+        program_id: antegen_thread_program::ID,
+        accounts: antegen_thread_program::accounts::ThreadInstructionReplace {
+            authority: client.payer_pubkey(),
+            system_program: system_program::ID,
+            thread: thread_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_thread_program::instruction::ThreadInstructionReplace {
+            index,
+            instruction,
+        }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, thread_pubkey)?;
+    Ok(())
+}
+
 pub fn pause(client: &Client, id: String) -> Result<(), CliError> {
     let thread_pubkey = Thread::pubkey(client.payer_pubkey(), id.into_bytes());
     let ix = Instruction {
@@ -211,6 +307,9 @@ pub fn reset(client: &Client, id: String) -> Result<(), CliError> {
 pub fn update(
     client: &Client,
     id: String,
+    fee_model: Option<FeeModel>,
+    fee_token_account: Option<Option<Pubkey>>,
+    pool_id: Option<u64>,
     rate_limit: Option<u64>,
     schedule: Option<String>,
 ) -> Result<(), CliError> {
@@ -225,8 +324,11 @@ pub fn update(
     };
     let settings = ThreadSettings {
         fee: None,
+        fee_model,
+        fee_token_account,
         instructions: None,
         name: None,
+        pool_id,
         rate_limit,
         trigger,
     };
@@ -234,6 +336,8 @@ pub fn update(
         program_id: antegen_thread_program::ID,
         accounts: antegen_thread_program::accounts::ThreadUpdate {
             authority: client.payer_pubkey(),
+            config: fee_token_account.flatten().map(|_| Config::pubkey()),
+            pool: pool_id.map(Pool::pubkey),
             system_program: system_program::ID,
             thread: thread_pubkey
         }.to_account_metas(Some(false)),
@@ -1,10 +1,14 @@
 use {
-    crate::{client::Client, errors::CliError}, anchor_lang::{
-        solana_program::{instruction::Instruction, system_program},
+    crate::{client::Client, cli::WorkerMetadataArgs, errors::CliError, processor::proposal}, anchor_lang::{
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            system_program,
+        },
         AccountDeserialize, InstructionData, ToAccountMetas,
     },
     antegen_network_program::state::{
-        Registry, Snapshot, SnapshotFrame, Worker, WorkerCommission, WorkerSettings,
+        Config, Pool, Proposal, ProposalAction, Registry, Snapshot, SnapshotFrame, Worker, WorkerCommission, WorkerMetadata,
+        WorkerSettings, WorkerStake, WorkerStats,
     }, antegen_utils::explorer::Explorer, solana_sdk::{
         pubkey::Pubkey,
         signature::{Keypair, Signer},
@@ -17,6 +21,8 @@ pub struct WorkerInfo {
     pub worker_pubkey: Pubkey,
     pub worker_commission_balance: u64,
     pub worker_commissions_pubkey: Pubkey,
+    pub worker_stake: Option<WorkerStake>,
+    pub worker_stats: Option<WorkerStats>,
     pub snapshot_frame: Option<SnapshotFrame>,
     pub explorer: Explorer,
 }
@@ -31,6 +37,14 @@ impl WorkerInfo {
             self.worker
         );
 
+        if let Some(stake) = &self.worker_stake {
+            println!("{:#?}", stake);
+        }
+
+        if let Some(stats) = &self.worker_stats {
+            println!("{:#?}", stats);
+        }
+
         if let Some(frame) = &self.snapshot_frame {
             println!("{:#?}", frame);
         }
@@ -54,6 +68,12 @@ pub fn _get(client: &Client, id: u64) -> Result<WorkerInfo, CliError> {
     let commission_balance = client.get_balance(&worker_commissions_pubkey).unwrap();
     let worker_commission_balance = commission_balance.saturating_sub(commission_min_rent);
 
+    // Get stake account. Workers registered before staking existed may not have one yet.
+    let worker_stake = client.get::<WorkerStake>(&WorkerStake::pubkey(worker_pubkey)).ok();
+
+    // Get stats account. It is created on the worker's first exec or snapshot if missing.
+    let worker_stats = client.get::<WorkerStats>(&WorkerStats::pubkey(worker_pubkey)).ok();
+
     // Get registry
     let registry_pubkey = Registry::pubkey();
     let registry_data = client
@@ -62,15 +82,16 @@ pub fn _get(client: &Client, id: u64) -> Result<WorkerInfo, CliError> {
     let registry = Registry::try_deserialize(&mut registry_data.as_slice())
         .map_err(|_err| CliError::AccountDataNotParsable(registry_pubkey.to_string()))?;
 
-    // Get snapshot frame
+    // Get snapshot frame. Frames are not indexed by worker id, so search the current snapshot.
     let snapshot_pubkey = Snapshot::pubkey(registry.current_epoch);
-    let snapshot_frame_pubkey = SnapshotFrame::pubkey(snapshot_pubkey, worker.id);
-    let snapshot_frame = match client.get_account_data(&snapshot_frame_pubkey) {
-        Ok(snapshot_frame_data) => {
-            Some(SnapshotFrame::try_deserialize(
-                &mut snapshot_frame_data.as_slice(),
-            ).map_err(|_err| CliError::AccountDataNotParsable(registry_pubkey.to_string()))?)
-        }
+    let snapshot_frame = match client.get::<Snapshot>(&snapshot_pubkey) {
+        Ok(snapshot) => (0..snapshot.total_frames)
+            .filter_map(|id| {
+                client
+                    .get::<SnapshotFrame>(&SnapshotFrame::pubkey(snapshot_pubkey, id))
+                    .ok()
+            })
+            .find(|frame| frame.worker.eq(&worker_pubkey)),
         Err(_) => None,
     };
 
@@ -79,6 +100,8 @@ pub fn _get(client: &Client, id: u64) -> Result<WorkerInfo, CliError> {
         worker_pubkey,
         worker_commission_balance,
         worker_commissions_pubkey,
+        worker_stake,
+        worker_stats,
         snapshot_frame,
         explorer: Explorer::from(client.client.url().clone()),  // Add this
     };
@@ -92,6 +115,67 @@ pub fn get(client: &Client, id: u64) -> Result<(), CliError> {
     Ok(())
 }
 
+pub fn list(client: &Client) -> Result<(), CliError> {
+    let registry = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountDataNotParsable(Registry::pubkey().to_string()))?;
+
+    // Closed workers are skipped.
+    for id in 0..registry.total_workers {
+        let worker_pubkey = Worker::pubkey(id);
+        if let Ok(worker) = client.get::<Worker>(&worker_pubkey) {
+            let field = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".into());
+            println!(
+                "Worker {}: {}\nStatus: {}\nCommission Rate: {}%\nName: {}\nWebsite: {}\nContact: {}\nPlugin Version: {}\nRegion: {}\n",
+                worker.id,
+                worker_pubkey,
+                if worker.is_active() { "active" } else { "deactivated" },
+                worker.commission_rate,
+                field(&worker.metadata.name),
+                field(&worker.metadata.website),
+                field(&worker.metadata.contact),
+                field(&worker.metadata.plugin_version),
+                field(&worker.metadata.region),
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn stats(client: &Client, id: u64) -> Result<(), CliError> {
+    let worker_info = _get(client, id)?;
+    let registry = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountDataNotParsable(Registry::pubkey().to_string()))?;
+
+    // Work performed in the current epoch.
+    let activity = worker_info
+        .worker_stats
+        .map(|stats| stats.activity(registry.current_epoch))
+        .unwrap_or_default();
+    println!(
+        "Epoch {}\nExecs: {}\nKickoffs: {}\nFees: {} lamports\nReimbursements: {} lamports",
+        registry.current_epoch,
+        activity.execs,
+        activity.kickoffs,
+        activity.fees,
+        activity.reimbursements
+    );
+
+    // Work recorded in the latest snapshot.
+    if let Some(frame) = worker_info.snapshot_frame {
+        println!(
+            "\nSnapshot {}\nExecs: {}\nKickoffs: {}\nFees: {} lamports\nReimbursements: {} lamports",
+            registry.current_epoch,
+            frame.execs,
+            frame.kickoffs,
+            frame.fees,
+            frame.reimbursements
+        );
+    }
+    Ok(())
+}
+
 pub fn create(client: &Client, signatory: Keypair, silent: bool) -> Result<(), CliError> {
     // Get registry
     let registry_pubkey: Pubkey = Registry::pubkey();
@@ -111,6 +195,8 @@ pub fn create(client: &Client, signatory: Keypair, silent: bool) -> Result<(), C
             worker: worker_pubkey,
             commission: WorkerCommission::pubkey(worker_pubkey),
             registry: Registry::pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            stats: WorkerStats::pubkey(worker_pubkey),
             system_program: system_program::ID
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::WorkerCreate {}.data(),
@@ -125,7 +211,13 @@ pub fn create(client: &Client, signatory: Keypair, silent: bool) -> Result<(), C
     Ok(())
 }
 
-pub fn update(client: &Client, id: u64, commission_rate: Option<u64>, signatory: Option<Keypair>) -> Result<(), CliError> {
+pub fn update(
+    client: &Client,
+    id: u64,
+    commission_rate: Option<u64>,
+    metadata: WorkerMetadataArgs,
+    signatory: Option<Keypair>,
+) -> Result<(), CliError> {
     // Derive worker keypair.
     let worker_pubkey: Pubkey = Worker::pubkey(id);
     let worker: Worker = client
@@ -135,12 +227,20 @@ pub fn update(client: &Client, id: u64, commission_rate: Option<u64>, signatory:
     // Build and submit tx.
     let settings: WorkerSettings = WorkerSettings {
         commission_rate: commission_rate.unwrap_or(worker.commission_rate),
+        metadata: WorkerMetadata {
+            contact: merge_metadata(worker.metadata.contact, metadata.contact),
+            name: merge_metadata(worker.metadata.name, metadata.name),
+            plugin_version: merge_metadata(worker.metadata.plugin_version, metadata.plugin_version),
+            region: merge_metadata(worker.metadata.region, metadata.region),
+            website: merge_metadata(worker.metadata.website, metadata.website),
+        },
         signatory: signatory.map_or(worker.signatory, |v| v.pubkey()),
     };
     let ix: Instruction = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::WorkerUpdate {
             authority: client.payer_pubkey(),
+            config: Config::pubkey(),
             system_program: system_program::ID,
             worker: worker_pubkey,
         }.to_account_metas(Some(false)),
@@ -150,3 +250,177 @@ pub fn update(client: &Client, id: u64, commission_rate: Option<u64>, signatory:
     get(client, worker.id)?;
     Ok(())
 }
+
+pub fn stake(client: &Client, id: u64, amount: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerStakeDeposit {
+            authority: client.payer_pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            system_program: system_program::ID,
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerStakeDeposit { amount }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn unbond(client: &Client, id: u64, amount: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerStakeUnbond {
+            authority: client.payer_pubkey(),
+            config: Config::pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerStakeUnbond { amount }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn withdraw(client: &Client, id: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerStakeWithdraw {
+            authority: client.payer_pubkey(),
+            config: Config::pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerStakeWithdraw {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn slash(client: &Client, id: u64, amount: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+
+    // Slashes are only made through proposals, so the worker can see them coming for the length of the timelock.
+    let action = ProposalAction::WorkerSlash { worker: worker_pubkey, amount };
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::ProposalCreate {
+            admin: config.admin,
+            config: Config::pubkey(),
+            proposal: Proposal::pubkey(config.total_proposals),
+            system_program: system_program::ID,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::ProposalCreate { action }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    proposal::get(client, config.total_proposals)?;
+    Ok(())
+}
+
+pub fn deactivate(client: &Client, id: u64) -> Result<(), CliError> {
+    let registry = client
+        .get::<Registry>(&Registry::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Registry::pubkey().to_string()))?;
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let mut ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerDeactivate {
+            authority: client.payer_pubkey(),
+            registry: Registry::pubkey(),
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerDeactivate {}.data(),
+    };
+
+    // The worker leaves every pool.
+    for pool_id in 0..registry.total_pools {
+        ix.accounts.push(AccountMeta::new(Pool::pubkey(pool_id), false));
+    }
+
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn claim(client: &Client, id: u64) -> Result<(), CliError> {
+    let config = client
+        .get::<Config>(&Config::pubkey())
+        .map_err(|_err| CliError::AccountNotFound(Config::pubkey().to_string()))?;
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+
+    // The worker's frame in the current snapshot decides whether it earns a commission.
+    let snapshot_frame = _get(client, id)?
+        .snapshot_frame
+        .map(|frame| SnapshotFrame::pubkey(frame.snapshot, frame.id));
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerClaim {
+            authority: client.payer_pubkey(),
+            commission: WorkerCommission::pubkey(worker_pubkey),
+            config: Config::pubkey(),
+            network_fee: config.network_fee_recipient,
+            registry: Registry::pubkey(),
+            snapshot_frame,
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerClaim {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn migrate(client: &Client, id: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerMigrate {
+            commission: WorkerCommission::pubkey(worker_pubkey),
+            payer: client.payer_pubkey(),
+            system_program: system_program::ID,
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerMigrate { id }.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    get(client, id)?;
+    Ok(())
+}
+
+pub fn close(client: &Client, id: u64) -> Result<(), CliError> {
+    let worker_pubkey: Pubkey = Worker::pubkey(id);
+    let stats_pubkey: Pubkey = WorkerStats::pubkey(worker_pubkey);
+    let stats = client.get::<WorkerStats>(&stats_pubkey).ok().map(|_| stats_pubkey);
+    let ix: Instruction = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerClose {
+            authority: client.payer_pubkey(),
+            commission: WorkerCommission::pubkey(worker_pubkey),
+            registry: Registry::pubkey(),
+            stake: WorkerStake::pubkey(worker_pubkey),
+            stats,
+            worker: worker_pubkey,
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerClose {}.data(),
+    };
+    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    println!("Closed worker {}", worker_pubkey);
+    Ok(())
+}
+
+/// Keeps the current metadata value unless a new one is given. An empty value clears the field.
+fn merge_metadata(current: Option<String>, update: Option<String>) -> Option<String> {
+    match update {
+        Some(value) if value.is_empty() => None,
+        Some(value) => Some(value),
+        None => current,
+    }
+}
@@ -1,5 +1,7 @@
 mod pool_rotation;
 mod thread_exec;
+mod worker_heartbeat;
 
 pub use pool_rotation::*;
 pub use thread_exec::*;
+pub use worker_heartbeat::*;
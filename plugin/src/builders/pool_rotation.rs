@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
 use anchor_lang::{
-    solana_program::instruction::Instruction,
+    solana_program::{instruction::Instruction, system_program},
     InstructionData, ToAccountMetas
 };
-use antegen_network_program::state::{Config, Pool, Registry, Worker};
+use antegen_network_program::state::{Config, Pool, Registry, Worker, WorkerStake};
 use log::info;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
@@ -14,6 +14,8 @@ use crate::pool_position::PoolPosition;
 pub async fn build_pool_rotation_tx<'a>(
     client: Arc<RpcClient>,
     keypair: &Keypair,
+    slot: u64,
+    pool: Pool,
     pool_position: PoolPosition,
     registry: Registry,
     worker_id: u64,
@@ -32,13 +34,23 @@ pub async fn build_pool_rotation_tx<'a>(
         return None;
     }
 
-    // Build rotation instruction to rotate the worker into pool 0.
+    // Exit early if the pool is full and the worker is not eligible to take the next seat.
+    let is_eligible =
+        pool.has_space() || (pool.is_rotation_due(slot) && registry.is_selected(worker_id));
+    if !is_eligible {
+        return None;
+    }
+
+    // Build rotation instruction to rotate the worker into the pool.
     let ix = Instruction {
         program_id: antegen_network_program::ID,
         accounts: antegen_network_program::accounts::PoolRotate {
             config: Config::pubkey(),
-            pool: Pool::pubkey(0),
+            pool: Pool::pubkey(pool.id),
+            registry: Registry::pubkey(),
             signatory: keypair.pubkey(),
+            stake: WorkerStake::pubkey(Worker::pubkey(worker_id)),
+            system_program: system_program::ID,
             worker: Worker::pubkey(worker_id),
         }.to_account_metas(Some(false)),
         data: antegen_network_program::instruction::PoolRotate {}.data(),
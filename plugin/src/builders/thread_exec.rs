@@ -3,8 +3,9 @@ use std::sync::Arc;
 use agave_geyser_plugin_interface::geyser_plugin_interface::{
     GeyserPluginError, Result as PluginResult,
 };
-use anchor_lang::{InstructionData, ToAccountMetas};
-use antegen_network_program::state::Worker;
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use antegen_network_program::state::{Config, Registry, Worker, WorkerCommission, WorkerStats};
 use antegen_thread_program::state::{Trigger, VersionedThread};
 use antegen_utils::thread::PAYER_PUBKEY;
 use log::info;
@@ -20,7 +21,6 @@ use solana_program::{
 };
 use solana_sdk::{
     account::Account,
-    commitment_config::CommitmentConfig,
     compute_budget::ComputeBudgetInstruction,
     message::{v0, VersionedMessage},
     signature::Keypair,
@@ -28,6 +28,8 @@ use solana_sdk::{
     transaction::VersionedTransaction,
 };
 
+use crate::executors::AccountGet;
+
 /// Max byte size of a serialized transaction.
 static TRANSACTION_MESSAGE_SIZE_LIMIT: usize = 1_232;
 
@@ -44,12 +46,19 @@ pub async fn build_thread_exec_tx(
     thread: VersionedThread,
     thread_pubkey: Pubkey,
     worker_id: u64,
+    min_lamports_per_1k_cu: u64,
 ) -> PluginResult<Option<VersionedTransaction>> {
     let now = std::time::Instant::now();
     let blockhash = client.get_latest_blockhash().await.unwrap();
     let signatory_pubkey = payer.pubkey();
     let worker_pubkey = Worker::pubkey(worker_id);
 
+    // Threads paying fees in tokens need the network's fee token configuration.
+    let config = match thread.fee_token_account() {
+        None => None,
+        Some(_) => client.get::<Config>(&Config::pubkey()).await.ok(),
+    };
+
     // Build the first instruction
     let first_instruction = if thread.next_instruction().is_some() {
         build_exec_ix(
@@ -57,6 +66,7 @@ pub async fn build_thread_exec_tx(
             thread_pubkey,
             signatory_pubkey,
             worker_pubkey,
+            config.as_ref(),
         )
     } else {
         build_kickoff_ix(
@@ -107,7 +117,7 @@ pub async fn build_thread_exec_tx(
                 RpcSimulateTransactionConfig {
                     sig_verify: false,
                     replace_recent_blockhash: true,
-                    commitment: Some(CommitmentConfig::processed()),
+                    commitment: Some(client.commitment()),
                     accounts: Some(RpcSimulateTransactionAccountsConfig {
                         encoding: Some(UiAccountEncoding::Base64Zstd),
                         addresses: vec![thread_pubkey.to_string()],
@@ -210,6 +220,7 @@ pub async fn build_thread_exec_tx(
                     thread_pubkey,
                     signatory_pubkey,
                     worker_pubkey,
+                    config.as_ref(),
                 ));
             }
         }
@@ -221,6 +232,34 @@ pub async fn build_thread_exec_tx(
         return Ok(None);
     }
 
+    // Skip threads whose fees do not cover the worker's minimum rate.
+    if let Some(units_consumed) = units_consumed {
+        let exec_count = successful_ixs
+            .iter()
+            .filter(|ix| {
+                ix.data
+                    .starts_with(antegen_thread_program::instruction::ThreadExec::DISCRIMINATOR)
+            })
+            .count() as u64;
+        if exec_count.gt(&0) {
+            let estimated_fee = thread
+                .fee_model()
+                .fee(thread.fee(), units_consumed / exec_count)
+                .unwrap_or(u64::MAX)
+                .saturating_mul(exec_count);
+            let minimum_fee = units_consumed
+                .saturating_mul(min_lamports_per_1k_cu)
+                .div_ceil(1_000);
+            if estimated_fee.lt(&minimum_fee) {
+                info!(
+                    "Thread is unprofitable - thread: {} estimated_fee: {} minimum_fee: {}",
+                    thread_pubkey, estimated_fee, minimum_fee
+                );
+                return Ok(None);
+            }
+        }
+    }
+
     // Update compute unit limit based on simulation
     if let Some(units_consumed) = units_consumed {
         let units_committed = std::cmp::min(
@@ -265,7 +304,13 @@ fn build_kickoff_ix(
         VersionedThread::V1(_) => Instruction {
             program_id: antegen_thread_program::ID,
             accounts: antegen_thread_program::accounts::ThreadKickoff {
+                network_program: antegen_network_program::ID,
+                pool: antegen_network_program::state::Pool::pubkey(thread.pool_id()),
+                registry: Registry::pubkey(),
                 signatory: signatory_pubkey,
+                stats: WorkerStats::pubkey(worker_pubkey),
+                stats_authority: antegen_network_program::thread_stats_authority(),
+                system_program: solana_program::system_program::ID,
                 thread: thread_pubkey,
                 worker: worker_pubkey,
             }
@@ -305,16 +350,41 @@ fn build_exec_ix(
     thread_pubkey: Pubkey,
     signatory_pubkey: Pubkey,
     worker_pubkey: Pubkey,
+    config: Option<&Config>,
 ) -> Instruction {
+    // If the thread pays fees in tokens, include the fee token accounts.
+    let commission_pubkey = WorkerCommission::pubkey(worker_pubkey);
+    let fee_token = match (thread.fee_token_account(), config) {
+        (Some(thread_token_account), Some(config)) => config
+            .fee_mint
+            .zip(config.fee_price_feed)
+            .map(|(fee_mint, fee_price_feed)| (thread_token_account, fee_mint, fee_price_feed)),
+        _ => None,
+    };
+
     // Build the instruction.
     let mut exec_ix = match thread {
         VersionedThread::V1(_) => Instruction {
             program_id: antegen_thread_program::ID,
             accounts: antegen_thread_program::accounts::ThreadExec {
-                commission: antegen_network_program::state::WorkerCommission::pubkey(worker_pubkey),
-                pool: antegen_network_program::state::Pool::pubkey(0),
+                commission: commission_pubkey,
+                commission_token_account: fee_token.map(|(_, fee_mint, _)| {
+                    get_associated_token_address(&commission_pubkey, &fee_mint)
+                }),
+                config: fee_token.map(|_| Config::pubkey()),
+                fee_mint: fee_token.map(|(_, fee_mint, _)| fee_mint),
+                fee_price_feed: fee_token.map(|(_, _, fee_price_feed)| fee_price_feed),
+                network_program: antegen_network_program::ID,
+                pool: antegen_network_program::state::Pool::pubkey(thread.pool_id()),
+                registry: Registry::pubkey(),
                 signatory: signatory_pubkey,
+                stats: WorkerStats::pubkey(worker_pubkey),
+                stats_authority: antegen_network_program::thread_stats_authority(),
+                system_program: solana_program::system_program::ID,
                 thread: thread_pubkey,
+                thread_token_account: fee_token
+                    .map(|(thread_token_account, _, _)| thread_token_account),
+                token_program: fee_token.map(|_| anchor_spl::token::ID),
                 worker: worker_pubkey,
             }
             .to_account_metas(Some(true)),
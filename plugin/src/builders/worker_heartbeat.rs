@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use anchor_lang::{
+    solana_program::{instruction::Instruction, system_program},
+    InstructionData, ToAccountMetas
+};
+use antegen_network_program::state::{Worker, WorkerStats};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+pub async fn build_worker_heartbeat_tx(
+    client: Arc<RpcClient>,
+    keypair: &Keypair,
+    worker_id: u64,
+) -> Option<Transaction> {
+    // Build heartbeat instruction to mark the worker as live.
+    let ix = Instruction {
+        program_id: antegen_network_program::ID,
+        accounts: antegen_network_program::accounts::WorkerHeartbeat {
+            signatory: keypair.pubkey(),
+            stats: WorkerStats::pubkey(Worker::pubkey(worker_id)),
+            system_program: system_program::ID,
+            worker: Worker::pubkey(worker_id),
+        }.to_account_metas(Some(false)),
+        data: antegen_network_program::instruction::WorkerHeartbeat {}.data(),
+    };
+
+    // Build and sign tx.
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&keypair.pubkey()));
+    tx.sign(&[keypair], client.get_latest_blockhash().await.ok()?);
+    Some(tx)
+}
@@ -4,7 +4,7 @@ use pyth_sdk_solana::{state::SolanaPriceAccount, PriceFeed};
 use agave_geyser_plugin_interface::geyser_plugin_interface::{
     GeyserPluginError, ReplicaAccountInfo,
 };
-use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey, sysvar};
 use static_pubkey::static_pubkey;
 
 static PYTH_ORACLE_PROGRAM_ID_MAINNET: Pubkey =
@@ -17,6 +17,7 @@ use log::info;
 #[derive(Debug)]
 pub enum AccountUpdateEvent {
     Clock { clock: Clock },
+    EpochSchedule { epoch_schedule: EpochSchedule },
     Thread { thread: VersionedThread },
     PriceFeed { price_feed: PriceFeed }
 }
@@ -39,6 +40,17 @@ impl TryFrom<&mut ReplicaAccountInfo<'_>> for AccountUpdateEvent {
             });
         }
 
+        // If the account is the sysvar epoch schedule, parse it.
+        if account_pubkey == sysvar::epoch_schedule::ID {
+            return Ok(AccountUpdateEvent::EpochSchedule {
+                epoch_schedule: bincode::deserialize::<EpochSchedule>(account_info.data).map_err(
+                    |_e| GeyserPluginError::AccountsUpdateError {
+                        msg: "Failed to parse sysvar epoch schedule account".into(),
+                    },
+                )?,
+            });
+        }
+
         // If the account belongs to the thread v1 program, parse it.
         if owner_pubkey == antegen_thread_program::ID && account_info.data.len() > 8 {
             let data = account_info.data.to_vec();
@@ -1,3 +1,4 @@
+pub mod tpu;
 pub mod tx;
 
 use std::{
@@ -16,14 +17,11 @@ use solana_client::{
     nonblocking::rpc_client::RpcClient,
 };
 use agave_geyser_plugin_interface::geyser_plugin_interface::Result as PluginResult;
-use solana_sdk::commitment_config::CommitmentConfig;
 use tokio::runtime::Runtime;
 use tx::TxExecutor;
 
 use crate::{config::PluginConfig, observers::Observers};
 
-static LOCAL_RPC_URL: &str = "http://127.0.0.1:8899";
-
 pub struct Executors {
     pub tx: Arc<TxExecutor>,
     pub client: Arc<RpcClient>,
@@ -34,10 +32,7 @@ impl Executors {
     pub fn new(config: PluginConfig) -> Self {
         Executors {
             tx: Arc::new(TxExecutor::new(config.clone())),
-            client: Arc::new(RpcClient::new_with_commitment(
-                LOCAL_RPC_URL.into(),
-                CommitmentConfig::processed(),
-            )),
+            client: Arc::new(rpc_client(&config)),
             lock: AtomicBool::new(false),
         }
     }
@@ -76,6 +71,17 @@ impl Executors {
             return Ok(());
         }
 
+        // Fetch the epoch schedule if the validator has not sent the sysvar, as it is only written at genesis.
+        if observers.thread.epoch_schedule.read().await.is_none() {
+            if let Ok(epoch_schedule) = self.client.get_epoch_schedule().await {
+                observers
+                    .thread
+                    .clone()
+                    .observe_epoch_schedule(epoch_schedule)
+                    .await?;
+            }
+        }
+
         // Process the slot on the observers.
         let executable_threads = observers.thread.clone().process_slot(slot).await?;
         info!("executable_threads: {:#?}", executable_threads);
@@ -110,6 +116,15 @@ impl Debug for Executors {
     }
 }
 
+/// Builds a client for the validator's RPC endpoint with the plugin's configured commitment and timeout.
+pub fn rpc_client(config: &PluginConfig) -> RpcClient {
+    RpcClient::new_with_timeout_and_commitment(
+        config.rpc_url.clone(),
+        config.rpc_timeout(),
+        config.commitment_config(),
+    )
+}
+
 #[async_trait]
 pub trait AccountGet {
     async fn get<T: AccountDeserialize>(&self, pubkey: &Pubkey) -> ClientResult<T>;
@@ -0,0 +1,106 @@
+use {
+    std::sync::Arc,
+    log::info,
+    solana_client::{
+        connection_cache::ConnectionCache,
+        nonblocking::tpu_client::TpuClient,
+        tpu_client::TpuClientConfig,
+    },
+    agave_geyser_plugin_interface::geyser_plugin_interface::{
+        GeyserPluginError,
+        Result as PluginResult,
+    },
+    solana_quic_client::{QuicConfig, QuicConnectionManager, QuicPool},
+    solana_sdk::transaction::Transaction,
+    tokio::sync::RwLock,
+    crate::config::PluginConfig,
+    super::rpc_client,
+};
+
+type QuicTpuClient = TpuClient<QuicPool, QuicConnectionManager, QuicConfig>;
+
+/// Submits transactions to the upcoming leaders through a long-lived TPU client.
+/// The client, its leader schedule and its QUIC connections are reused across slots,
+/// and rebuilt on the next send after a failure.
+pub struct TpuSender {
+    pub config: PluginConfig,
+    pub client: RwLock<Option<Arc<QuicTpuClient>>>,
+}
+
+impl TpuSender {
+    pub fn new(config: PluginConfig) -> Self {
+        Self {
+            config,
+            client: RwLock::new(None),
+        }
+    }
+
+    /// Send a transaction to the upcoming leaders.
+    pub async fn send_transaction(&self, tx: &Transaction) -> PluginResult<()> {
+        let result = self.client().await?.try_send_transaction(tx).await;
+        self.refresh_on_error(result).await
+    }
+
+    /// Send a batch of serialized transactions to the upcoming leaders.
+    pub async fn send_wire_transaction_batch(&self, wire_txs: Vec<Vec<u8>>) -> PluginResult<()> {
+        let result = self
+            .client()
+            .await?
+            .try_send_wire_transaction_batch(wire_txs)
+            .await;
+        self.refresh_on_error(result).await
+    }
+
+    /// Get the current client, building a new one if there is none.
+    async fn client(&self) -> PluginResult<Arc<QuicTpuClient>> {
+        if let Some(client) = self.client.read().await.as_ref() {
+            return Ok(client.clone());
+        }
+
+        // Another task may have built the client while this one waited for the lock.
+        let mut w_client = self.client.write().await;
+        if let Some(client) = w_client.as_ref() {
+            return Ok(client.clone());
+        }
+        let connection_cache = match ConnectionCache::new_quic(
+            "tpu_client",
+            self.config.tpu_connection_pool_size,
+        ) {
+            ConnectionCache::Quic(cache) => cache,
+            ConnectionCache::Udp(_) => {
+                return Err(GeyserPluginError::Custom(
+                    "Invalid TPU connection cache".into(),
+                ))
+            }
+        };
+        let client = Arc::new(
+            TpuClient::new_with_connection_cache(
+                Arc::new(rpc_client(&self.config)),
+                &self.config.websocket_url,
+                TpuClientConfig {
+                    fanout_slots: self.config.tpu_fanout_slots,
+                },
+                connection_cache,
+            )
+            .await
+            .map_err(|err| {
+                GeyserPluginError::Custom(format!("Failed to build TPU client: {}", err).into())
+            })?,
+        );
+        *w_client = Some(client.clone());
+        Ok(client)
+    }
+
+    /// Drop the client after a failed send, so the next send reconnects with a fresh leader schedule.
+    /// Dropping the last reference to a client closes its connections.
+    async fn refresh_on_error<E: std::fmt::Debug>(&self, result: Result<(), E>) -> PluginResult<()> {
+        if let Err(err) = result {
+            info!("TPU send failed, refreshing client: {:?}", err);
+            *self.client.write().await = None;
+            return Err(GeyserPluginError::Custom(
+                format!("Failed to send transaction: {:?}", err).into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -8,36 +8,28 @@ use {
         },
     },
     bincode::serialize,
-    antegen_network_program::state::{Pool, Registry, Worker},
+    antegen_network_program::state::{Config, Pool, Registry, Worker},
     antegen_thread_program::state::VersionedThread,
+    antegen_utils::thread::Trigger,
     log::info,
-    solana_client::{
-        nonblocking::{rpc_client::RpcClient, tpu_client::TpuClient},
-        rpc_config::RpcSimulateTransactionConfig,
-        tpu_client::TpuClientConfig,
-    },
+    solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig},
     agave_geyser_plugin_interface::geyser_plugin_interface::{
         GeyserPluginError, 
         Result as PluginResult,
     },
     solana_program::pubkey::Pubkey,
-    solana_quic_client::{QuicConfig, QuicConnectionManager, QuicPool},
     solana_sdk::{
-        commitment_config::CommitmentConfig,
         signature::{Keypair, Signature},
         transaction::{Transaction, VersionedTransaction},
     },
     tokio::{runtime::Runtime, sync::RwLock},
     crate::{config::PluginConfig, pool_position::PoolPosition, utils::read_or_new_keypair},
-    super::AccountGet,
+    super::{rpc_client, tpu::TpuSender, AccountGet},
 };
 
 /// Number of slots to wait before checking for a confirmed transaction.
 static TRANSACTION_CONFIRMATION_PERIOD: u64 = 24;
 
-/// Number of slots to wait before trying to execute a thread while not in the pool.
-static THREAD_TIMEOUT_WINDOW: u64 = 24;
-
 /// Number of times to retry a thread simulation.
 static MAX_THREAD_SIMULATION_FAILURES: u32 = 5;
 
@@ -47,14 +39,24 @@ static EXPONENTIAL_BACKOFF_CONSTANT: u32 = 2;
 /// The number of slots to wait since the last rotation attempt.
 static ROTATION_CONFIRMATION_PERIOD: u64 = 16;
 
+/// Number of heartbeats to post within each heartbeat timeout.
+static HEARTBEATS_PER_TIMEOUT: u64 = 4;
+
+/// Number of slots to reuse the fetched pools for before fetching them again.
+static POOL_CACHE_PERIOD: u64 = 8;
+
 /// TxExecutor
 pub struct TxExecutor {
     pub config: PluginConfig,
     pub executable_threads: RwLock<HashMap<Pubkey, ExecutableThreadMetadata>>,
     pub transaction_history: RwLock<HashMap<Pubkey, TransactionMetadata>>,
-    pub rotation_history: RwLock<Option<TransactionMetadata>>,
+    pub rotation_history: RwLock<HashMap<u64, TransactionMetadata>>,
     pub dropped_threads: AtomicU64,
+    pub heartbeat_slot: AtomicU64,
+    pub pool_cache: RwLock<HashMap<u64, Pool>>,
+    pub pool_cache_slot: AtomicU64,
     pub keypair: Keypair,
+    pub tpu: TpuSender,
 }
 
 #[derive(Debug)]
@@ -76,9 +78,13 @@ impl TxExecutor {
             config: config.clone(),
             executable_threads: RwLock::new(HashMap::new()),
             transaction_history: RwLock::new(HashMap::new()),
-            rotation_history: RwLock::new(None),
+            rotation_history: RwLock::new(HashMap::new()),
             dropped_threads: AtomicU64::new(0),
-            keypair: read_or_new_keypair(config.keypath),
+            heartbeat_slot: AtomicU64::new(0),
+            pool_cache: RwLock::new(HashMap::new()),
+            pool_cache_slot: AtomicU64::new(0),
+            keypair: read_or_new_keypair(config.keypath.clone()),
+            tpu: TpuSender::new(config),
         }
     }
 
@@ -123,39 +129,87 @@ impl TxExecutor {
             .await
             .ok();
 
-        // Get self worker's position in the delegate pool.
+        // Post a heartbeat so the worker is not evicted from its pools.
+        self.clone()
+            .execute_worker_heartbeat_tx(client.clone(), slot)
+            .await
+            .ok();
+
+        // Get self worker's position in each of the network's pools.
         let worker_pubkey = Worker::pubkey(self.config.worker_id);
-        if let Ok(pool_position) = (client.get::<Pool>(&Pool::pubkey(0)).await).map(|pool| {
-            let workers = &mut pool.workers.clone();
-            PoolPosition {
+        let registry = match client.get::<Registry>(&Registry::pubkey()).await {
+            Err(_err) => return Ok(()),
+            Ok(registry) => registry,
+        };
+        let pools = self.get_pools(client.clone(), &registry, slot).await;
+        for (pool_id, pool) in pools.iter() {
+            let pool_id = *pool_id;
+            let pool_position = PoolPosition {
                 current_position: pool
                     .workers
                     .iter()
                     .position(|k| k.eq(&worker_pubkey))
                     .map(|i| i as u64),
-                workers: workers.clone(),
-            }
-        }) {
-            info!("pool_position: {:?}", pool_position);
+                workers: pool.workers.clone(),
+            };
+            info!("pool_id: {} pool_position: {:?}", pool_id, pool_position);
 
-            // Rotate into the worker pool.
-            if pool_position.current_position.is_none() {
+            // Rotate into the worker pools this worker is configured to serve.
+            if pool_position.current_position.is_none() && self.config.pool_ids.contains(&pool_id) {
                 self.clone()
-                    .execute_pool_rotate_txs(client.clone(), slot, pool_position.clone())
+                    .execute_pool_rotate_txs(
+                        client.clone(),
+                        slot,
+                        pool.clone(),
+                        pool_position.clone(),
+                        registry.clone(),
+                    )
                     .await
                     .ok();
             }
-
-            // Execute thread transactions.
-            self.clone()
-                .execute_thread_exec_txs(client.clone(), slot, pool_position, runtime.clone())
-                .await
-                .ok();
         }
 
+        // Execute thread transactions.
+        self.clone()
+            .execute_thread_exec_txs(
+                client.clone(),
+                slot,
+                Arc::new(pools),
+                registry.nonce,
+                runtime.clone(),
+            )
+            .await
+            .ok();
+
         Ok(())
     }
 
+    /// Returns the network's pools, fetching them again once the cached copies are too old or a pool was added.
+    async fn get_pools(
+        &self,
+        client: Arc<RpcClient>,
+        registry: &Registry,
+        slot: u64,
+    ) -> HashMap<u64, Pool> {
+        let r_pool_cache = self.pool_cache.read().await;
+        let is_fresh = slot < self.pool_cache_slot.load(Ordering::Relaxed) + POOL_CACHE_PERIOD
+            && (r_pool_cache.len() as u64).eq(&registry.total_pools);
+        if is_fresh {
+            return r_pool_cache.clone();
+        }
+        drop(r_pool_cache);
+
+        let mut pools: HashMap<u64, Pool> = HashMap::new();
+        for pool_id in 0..registry.total_pools {
+            if let Ok(pool) = client.get::<Pool>(&Pool::pubkey(pool_id)).await {
+                pools.insert(pool_id, pool);
+            }
+        }
+        *self.pool_cache.write().await = pools.clone();
+        self.pool_cache_slot.store(slot, Ordering::Relaxed);
+        pools
+    }
+
     async fn process_retries(
         self: Arc<Self>,
         client: Arc<RpcClient>,
@@ -185,10 +239,7 @@ impl TxExecutor {
         let mut successful_threads: HashSet<Pubkey> = HashSet::new();
         for data in checkable_transactions {
             match client
-                .get_signature_status_with_commitment(
-                    &data.signature,
-                    CommitmentConfig::processed(),
-                )
+                .get_signature_status_with_commitment(&data.signature, client.commitment())
                 .await
             {
                 Err(_err) => {}
@@ -245,12 +296,14 @@ impl TxExecutor {
         self: Arc<Self>,
         client: Arc<RpcClient>,
         slot: u64,
+        pool: Pool,
         pool_position: PoolPosition,
+        registry: Registry,
     ) -> PluginResult<()> {
+        let pool_id = pool.id;
         let r_rotation_history = self.rotation_history.read().await;
         log::info!("Rotation history {:?}", r_rotation_history);
-        let should_attempt = if r_rotation_history.is_some() {
-            let rotation_history = r_rotation_history.as_ref().unwrap();
+        let should_attempt = if let Some(rotation_history) = r_rotation_history.get(&pool_id) {
             if slot
                 > rotation_history
                     .sent_slot
@@ -279,10 +332,11 @@ impl TxExecutor {
         if !should_attempt {
             return Ok(());
         }
-        let registry = client.get::<Registry>(&Registry::pubkey()).await.unwrap();
         if let Some(tx) = crate::builders::build_pool_rotation_tx(
             client.clone(),
             &self.keypair,
+            slot,
+            pool,
             pool_position,
             registry,
             self.config.worker_id,
@@ -292,41 +346,62 @@ impl TxExecutor {
             self.clone().simulate_tx(&tx).await?;
             self.clone().submit_tx(&tx).await?;
             let mut w_rotation_history = self.rotation_history.write().await;
-            *w_rotation_history = Some(TransactionMetadata {
-                due_slot: slot,
-                sent_slot: slot,
-                signature: tx.signatures[0],
-            });
+            w_rotation_history.insert(
+                pool_id,
+                TransactionMetadata {
+                    due_slot: slot,
+                    sent_slot: slot,
+                    signature: tx.signatures[0],
+                },
+            );
             drop(w_rotation_history);
         }
         Ok(())
     }
 
-    async fn get_executable_threads(
+    async fn execute_worker_heartbeat_tx(
         self: Arc<Self>,
-        pool_position: PoolPosition,
+        client: Arc<RpcClient>,
         slot: u64,
-    ) -> PluginResult<Vec<(Pubkey, u64)>> {
+    ) -> PluginResult<()> {
+        // Exit early if the network does not evict workers.
+        let config = match client.get::<Config>(&Config::pubkey()).await {
+            Err(_err) => return Ok(()),
+            Ok(config) => config,
+        };
+        if config.heartbeat_timeout.eq(&0) {
+            return Ok(());
+        }
+
+        // Exit early if a heartbeat has been posted recently.
+        let heartbeat_period = (config.heartbeat_timeout / HEARTBEATS_PER_TIMEOUT).max(1);
+        if slot < self.heartbeat_slot.load(Ordering::Relaxed) + heartbeat_period {
+            return Ok(());
+        }
+
+        if let Some(tx) = crate::builders::build_worker_heartbeat_tx(
+            client.clone(),
+            &self.keypair,
+            self.config.worker_id,
+        )
+        .await
+        {
+            self.clone().simulate_tx(&tx).await?;
+            self.clone().submit_tx(&tx).await?;
+            self.heartbeat_slot.store(slot, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    async fn get_executable_threads(self: Arc<Self>, slot: u64) -> PluginResult<Vec<(Pubkey, u64)>> {
         // Get the set of thread pubkeys that are executable.
-        // Note we parallelize using rayon because this work is CPU heavy.
+        // Threads assigned to other workers are deferred once their pool is known.
         let r_executable_threads = self.executable_threads.read().await;
-        let thread_pubkeys =
-            if pool_position.current_position.is_none() && !pool_position.workers.is_empty() {
-                // This worker is not in the pool. Get pubkeys of threads that are beyond the timeout window.
-                r_executable_threads
-                    .iter()
-                    .filter(|(_pubkey, metadata)| slot > metadata.due_slot + THREAD_TIMEOUT_WINDOW)
-                    .filter(|(_pubkey, metadata)| slot >= exponential_backoff_threshold(*metadata))
-                    .map(|(pubkey, metadata)| (*pubkey, metadata.due_slot))
-                    .collect::<Vec<(Pubkey, u64)>>()
-            } else {
-                // This worker is in the pool. Get pubkeys executable threads.
-                r_executable_threads
-                    .iter()
-                    .filter(|(_pubkey, metadata)| slot >= exponential_backoff_threshold(*metadata))
-                    .map(|(pubkey, metadata)| (*pubkey, metadata.due_slot))
-                    .collect::<Vec<(Pubkey, u64)>>()
-            };
+        let thread_pubkeys = r_executable_threads
+            .iter()
+            .filter(|(_pubkey, metadata)| slot >= exponential_backoff_threshold(*metadata))
+            .map(|(pubkey, metadata)| (*pubkey, metadata.due_slot))
+            .collect::<Vec<(Pubkey, u64)>>();
         drop(r_executable_threads);
         Ok(thread_pubkeys)
     }
@@ -335,12 +410,13 @@ impl TxExecutor {
         self: Arc<Self>,
         client: Arc<RpcClient>,
         observed_slot: u64,
-        pool_position: PoolPosition,
+        pools: Arc<HashMap<u64, Pool>>,
+        nonce: u64,
         runtime: Arc<Runtime>,
     ) -> PluginResult<()> {
         let executable_threads = self
             .clone()
-            .get_executable_threads(pool_position, observed_slot)
+            .get_executable_threads(observed_slot)
             .await?;
         if executable_threads.is_empty() {
             return Ok(());
@@ -356,6 +432,8 @@ impl TxExecutor {
                     observed_slot,
                     *due_slot,
                     *thread_pubkey,
+                    pools.clone(),
+                    nonce,
                 ))
             })
             .collect();
@@ -379,11 +457,7 @@ impl TxExecutor {
             .collect::<Vec<Vec<u8>>>();
 
         // Batch submit transactions to the leader.
-        match get_tpu_client()
-            .await
-            .try_send_wire_transaction_batch(wire_txs)
-            .await
-        {
+        match self.tpu.send_wire_transaction_batch(wire_txs).await {
             Err(err) => {
                 info!("Failed to sent transaction batch: {:?}", err);
             }
@@ -416,6 +490,8 @@ impl TxExecutor {
         observed_slot: u64,
         due_slot: u64,
         thread_pubkey: Pubkey,
+        pools: Arc<HashMap<u64, Pool>>,
+        nonce: u64,
     ) -> Option<(Pubkey, VersionedTransaction, u64)> {
         let thread = match client.clone().get::<VersionedThread>(&thread_pubkey).await {
             Err(_err) => {
@@ -436,6 +512,30 @@ impl TxExecutor {
             }
         }
 
+        // Attempt this worker's own assignments first. Threads assigned to other pool members are deferred
+        // until the grace period has passed, and workers outside the pool wait beyond the timeout window.
+        if let Some(pool) = pools.get(&thread.pool_id()) {
+            let worker_pubkey = Worker::pubkey(self.config.worker_id);
+            let may_process = if pool.workers.contains(&worker_pubkey) {
+                pool.is_assigned(
+                    worker_pubkey,
+                    thread_pubkey,
+                    thread.due_slot(observed_slot),
+                    nonce,
+                    observed_slot,
+                )
+            } else {
+                // Kickoffs of event triggers are left to the pool's members.
+                let is_event_kickoff = thread.next_instruction().is_none()
+                    && matches!(thread.trigger(), Trigger::Account { .. } | Trigger::Pyth { .. });
+                pool.admits(worker_pubkey, due_slot, observed_slot)
+                    && (pool.workers.is_empty() || !is_event_kickoff)
+            };
+            if !may_process {
+                return None;
+            }
+        }
+
         if let Ok(tx) = crate::builders::build_thread_exec_tx(
             client.clone(),
             &self.keypair,
@@ -443,6 +543,7 @@ impl TxExecutor {
             thread,
             thread_pubkey,
             self.config.worker_id,
+            self.config.min_lamports_per_1k_cu,
         )
         .await
         {
@@ -491,12 +592,13 @@ impl TxExecutor {
     }
 
     async fn simulate_tx(self: Arc<Self>, tx: &Transaction) -> PluginResult<Transaction> {
-        RpcClient::new_with_commitment(LOCAL_RPC_URL.into(), CommitmentConfig::processed())
+        let client = rpc_client(&self.config);
+        client
             .simulate_transaction_with_config(
                 tx,
                 RpcSimulateTransactionConfig {
                     replace_recent_blockhash: false,
-                    commitment: Some(CommitmentConfig::processed()),
+                    commitment: Some(client.commitment()),
                     ..RpcSimulateTransactionConfig::default()
                 },
             )
@@ -517,11 +619,7 @@ impl TxExecutor {
     }
 
     async fn submit_tx(self: Arc<Self>, tx: &Transaction) -> PluginResult<Transaction> {
-        if !get_tpu_client().await.send_transaction(tx).await {
-            return Err(GeyserPluginError::Custom(
-                "Failed to send transaction".into(),
-            ));
-        }
+        self.tpu.send_transaction(tx).await?;
         Ok(tx.clone())
     }
 }
@@ -535,24 +633,3 @@ impl Debug for TxExecutor {
 fn exponential_backoff_threshold(metadata: &ExecutableThreadMetadata) -> u64 {
     metadata.due_slot + EXPONENTIAL_BACKOFF_CONSTANT.pow(metadata.simulation_failures) as u64 - 1
 }
-
-static LOCAL_RPC_URL: &str = "http://127.0.0.1:8899";
-static LOCAL_WEBSOCKET_URL: &str = "ws://127.0.0.1:8900";
-
-// Do not use a static ref here.
-// -> The quic connections are dropped only when TpuClient is dropped
-async fn get_tpu_client() -> TpuClient<QuicPool, QuicConnectionManager, QuicConfig> {
-    let rpc_client = Arc::new(RpcClient::new_with_commitment(
-        LOCAL_RPC_URL.into(),
-        CommitmentConfig::processed(),
-    ));
-    let tpu_client = TpuClient::new(
-        "tpu_client",
-        rpc_client,
-        LOCAL_WEBSOCKET_URL.into(),
-        TpuClientConfig { fanout_slots: TRANSACTION_CONFIRMATION_PERIOD },
-    )
-    .await
-    .unwrap();
-    tpu_client
-}
@@ -13,7 +13,7 @@ use pyth_sdk_solana::PriceFeed;
 use agave_geyser_plugin_interface::geyser_plugin_interface::{
     GeyserPluginError, Result as PluginResult,
 };
-use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey};
 use tokio::sync::RwLock;
 
 pub struct ThreadObserver {
@@ -23,6 +23,10 @@ pub struct ThreadObserver {
     // Integer tracking the current epoch.
     pub current_epoch: AtomicU64,
 
+    // The cluster's epoch schedule, used to find the first slot of an epoch.
+    // Unknown until the sysvar is observed or fetched over RPC.
+    pub epoch_schedule: RwLock<Option<EpochSchedule>>,
+
     // The set of threads with an account trigger.
     // Map from account pubkeys to the set of threads listening for an account update.
     pub account_threads: RwLock<HashMap<Pubkey, HashSet<Pubkey>>>,
@@ -38,7 +42,8 @@ pub struct ThreadObserver {
     pub slot_threads: RwLock<HashMap<u64, HashSet<Pubkey>>>,
 
     // The set of threads with an epoch trigger.
-    pub epoch_threads: RwLock<HashMap<u64, HashSet<Pubkey>>>,
+    // Map from epoch numbers to the list of threads scheduled for that epoch.
+    pub epoch_threads: RwLock<HashMap<u64, HashSet<EpochThread>>>,
 
     // The set of threads with a pyth trigger.
     pub pyth_threads: RwLock<HashMap<Pubkey, HashSet<PythThread>>>,
@@ -47,6 +52,12 @@ pub struct ThreadObserver {
     pub updated_accounts: RwLock<HashSet<Pubkey>>,
 }
 
+#[derive(Eq, Hash, PartialEq)]
+pub struct EpochThread {
+    pub thread_pubkey: Pubkey,
+    pub offset_slots: u64,
+}
+
 #[derive(Eq, Hash, PartialEq)]
 pub struct PythThread {
     pub thread_pubkey: Pubkey,
@@ -59,6 +70,7 @@ impl ThreadObserver {
         Self {
             clocks: RwLock::new(HashMap::new()),
             current_epoch: AtomicU64::new(0),
+            epoch_schedule: RwLock::new(None),
             account_threads: RwLock::new(HashMap::new()),
             cron_threads: RwLock::new(HashMap::new()),
             now_threads: RwLock::new(HashSet::new()),
@@ -84,8 +96,6 @@ impl ThreadObserver {
             w_cron_threads.retain(|target_timestamp, thread_pubkeys| {
                 let is_due = clock.unix_timestamp >= *target_timestamp;
                 if is_due {
-                    self.current_epoch
-                        .fetch_max(clock.epoch, std::sync::atomic::Ordering::Relaxed);
                     for pubkey in thread_pubkeys.iter() {
                         executable_threads.insert(*pubkey);
                     }
@@ -127,16 +137,48 @@ impl ThreadObserver {
         let current_epoch = self
             .current_epoch
             .load(std::sync::atomic::Ordering::Relaxed);
-        w_epoch_threads.retain(|target_epoch, thread_pubkeys| {
+        let first_slot_in_epoch = self
+            .epoch_schedule
+            .read()
+            .await
+            .as_ref()
+            .map(|epoch_schedule| epoch_schedule.get_first_slot_in_epoch(current_epoch));
+        let mut offset_threads: Vec<(u64, Pubkey)> = vec![];
+        w_epoch_threads.retain(|target_epoch, epoch_threads| {
             let is_due = current_epoch >= *target_epoch;
-            if is_due {
-                for pubkey in thread_pubkeys.iter() {
-                    executable_threads.insert(*pubkey);
-                }
+            if !is_due {
+                return true;
             }
-            !is_due
+            epoch_threads.retain(|epoch_thread| {
+                if epoch_thread.offset_slots.eq(&0) {
+                    executable_threads.insert(epoch_thread.thread_pubkey);
+                    return false;
+                }
+
+                // Threads waiting for an offset into the epoch are moved to the slot index,
+                // once the epoch schedule is known to find the epoch's first slot.
+                let Some(first_slot_in_epoch) = first_slot_in_epoch else {
+                    return true;
+                };
+                offset_threads.push((
+                    first_slot_in_epoch.saturating_add(epoch_thread.offset_slots),
+                    epoch_thread.thread_pubkey,
+                ));
+                false
+            });
+            !epoch_threads.is_empty()
         });
         drop(w_epoch_threads);
+        if !offset_threads.is_empty() {
+            let mut w_slot_threads = self.slot_threads.write().await;
+            for (target_slot, thread_pubkey) in offset_threads {
+                w_slot_threads
+                    .entry(target_slot)
+                    .or_insert_with(HashSet::new)
+                    .insert(thread_pubkey);
+            }
+            drop(w_slot_threads);
+        }
 
         // Get the set of immediate threads.
         let mut w_now_threads = self.now_threads.write().await;
@@ -153,6 +195,18 @@ impl ThreadObserver {
         let mut w_clocks = self.clocks.write().await;
         w_clocks.insert(clock.slot, clock.clone());
         drop(w_clocks);
+        self.current_epoch
+            .fetch_max(clock.epoch, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub async fn observe_epoch_schedule(
+        self: Arc<Self>,
+        epoch_schedule: EpochSchedule,
+    ) -> PluginResult<()> {
+        let mut w_epoch_schedule = self.epoch_schedule.write().await;
+        *w_epoch_schedule = Some(epoch_schedule);
+        drop(w_epoch_schedule);
         Ok(())
     }
 
@@ -336,11 +390,51 @@ impl ThreadObserver {
                     w_epoch_threads
                         .entry(epoch)
                         .and_modify(|v| {
-                            v.insert(thread_pubkey);
+                            v.insert(EpochThread {
+                                thread_pubkey,
+                                offset_slots: 0,
+                            });
                         })
                         .or_insert_with(|| {
                             let mut v = HashSet::new();
-                            v.insert(thread_pubkey);
+                            v.insert(EpochThread {
+                                thread_pubkey,
+                                offset_slots: 0,
+                            });
+                            v
+                        });
+                    drop(w_epoch_threads);
+                }
+                Trigger::EveryEpoch { offset_slots } => {
+                    // Find the next epoch the thread should run in.
+                    let target_epoch = match thread.exec_context() {
+                        None => self
+                            .current_epoch
+                            .load(std::sync::atomic::Ordering::Relaxed),
+                        Some(exec_context) => match exec_context.trigger_context {
+                            TriggerContext::Epoch { started_at } => started_at + 1,
+                            _ => {
+                                return Err(GeyserPluginError::Custom(
+                                    "Invalid exec context".into(),
+                                ))
+                            }
+                        },
+                    };
+                    let mut w_epoch_threads = self.epoch_threads.write().await;
+                    w_epoch_threads
+                        .entry(target_epoch)
+                        .and_modify(|v| {
+                            v.insert(EpochThread {
+                                thread_pubkey,
+                                offset_slots,
+                            });
+                        })
+                        .or_insert_with(|| {
+                            let mut v = HashSet::new();
+                            v.insert(EpochThread {
+                                thread_pubkey,
+                                offset_slots,
+                            });
                             v
                         });
                     drop(w_epoch_threads);
@@ -122,6 +122,15 @@ impl GeyserPlugin for AntegenPlugin {
                             .await
                             .ok();
                     }
+                    AccountUpdateEvent::EpochSchedule { epoch_schedule } => {
+                        inner
+                            .observers
+                            .thread
+                            .clone()
+                            .observe_epoch_schedule(epoch_schedule)
+                            .await
+                            .ok();
+                    }
                     AccountUpdateEvent::Thread { thread } => {
                         inner
                             .observers
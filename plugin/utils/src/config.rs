@@ -6,34 +6,100 @@ use {
     agave_geyser_plugin_interface::geyser_plugin_interface::{
         GeyserPluginError, Result as PluginResult,
     },
-    std::{fs::File, path::Path},
+    solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel},
+    std::{fs::File, path::Path, time::Duration},
 };
 
 static DEFAULT_TRANSACTION_TIMEOUT_THRESHOLD: u64 = 150;
 static DEFAULT_THREAD_COUNT: usize = 10;
+static DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+static DEFAULT_RPC_TIMEOUT_SECS: u64 = 30;
+static DEFAULT_WEBSOCKET_URL: &str = "ws://127.0.0.1:8900";
+static DEFAULT_TPU_CONNECTION_POOL_SIZE: usize = 4;
+static DEFAULT_TPU_FANOUT_SLOTS: u64 = 24;
 
 /// Plugin config.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PluginConfig {
+    /// The commitment level the plugin reads accounts and simulates transactions at.
+    #[serde(default = "default_commitment")]
+    pub commitment: CommitmentLevel,
     pub keypath: Option<String>,
     pub libpath: Option<String>,
+    /// The minimum fee, in lamports per 1,000 compute units, a thread must pay to be executed.
+    #[serde(default)]
+    pub min_lamports_per_1k_cu: u64,
+    /// The ids of the worker pools this worker should rotate into.
+    #[serde(default = "default_pool_ids")]
+    pub pool_ids: Vec<u64>,
+    /// The number of seconds to wait on a request to the validator's RPC before giving up.
+    #[serde(default = "default_rpc_timeout_secs")]
+    pub rpc_timeout_secs: u64,
+    /// The validator's JSON RPC endpoint.
+    #[serde(default = "default_rpc_url")]
+    pub rpc_url: String,
     pub thread_count: usize,
+    /// The number of QUIC connections kept open to each leader's TPU.
+    #[serde(default = "default_tpu_connection_pool_size")]
+    pub tpu_connection_pool_size: usize,
+    /// The number of upcoming leader slots transactions are sent to.
+    #[serde(default = "default_tpu_fanout_slots")]
+    pub tpu_fanout_slots: u64,
     pub transaction_timeout_threshold: u64,
+    /// The validator's websocket endpoint, used to follow the leader schedule for TPU submission.
+    #[serde(default = "default_websocket_url")]
+    pub websocket_url: String,
     pub worker_id: u64,
 }
 
 impl Default for PluginConfig {
     fn default() -> Self {
         Self {
+            commitment: default_commitment(),
             keypath: None,
             libpath: None,
+            min_lamports_per_1k_cu: 0,
+            pool_ids: default_pool_ids(),
+            rpc_timeout_secs: default_rpc_timeout_secs(),
+            rpc_url: default_rpc_url(),
             transaction_timeout_threshold: DEFAULT_TRANSACTION_TIMEOUT_THRESHOLD,
             thread_count: DEFAULT_THREAD_COUNT,
+            tpu_connection_pool_size: default_tpu_connection_pool_size(),
+            tpu_fanout_slots: default_tpu_fanout_slots(),
+            websocket_url: default_websocket_url(),
             worker_id: 0,
         }
     }
 }
 
+fn default_commitment() -> CommitmentLevel {
+    CommitmentLevel::Processed
+}
+
+fn default_pool_ids() -> Vec<u64> {
+    vec![0]
+}
+
+fn default_rpc_timeout_secs() -> u64 {
+    DEFAULT_RPC_TIMEOUT_SECS
+}
+
+fn default_rpc_url() -> String {
+    DEFAULT_RPC_URL.into()
+}
+
+fn default_tpu_connection_pool_size() -> usize {
+    DEFAULT_TPU_CONNECTION_POOL_SIZE
+}
+
+fn default_tpu_fanout_slots() -> u64 {
+    DEFAULT_TPU_FANOUT_SLOTS
+}
+
+fn default_websocket_url() -> String {
+    DEFAULT_WEBSOCKET_URL.into()
+}
+
 impl PluginConfig {
     /// Read plugin from JSON file.
     pub fn read_from<P: AsRef<Path>>(config_path: P) -> PluginResult<Self> {
@@ -42,4 +108,16 @@ impl PluginConfig {
             .map_err(|e| GeyserPluginError::ConfigFileReadError { msg: e.to_string() })?;
         Ok(this)
     }
+
+    /// The commitment the plugin's RPC clients are created with.
+    pub fn commitment_config(&self) -> CommitmentConfig {
+        CommitmentConfig {
+            commitment: self.commitment,
+        }
+    }
+
+    /// The timeout of the plugin's RPC requests.
+    pub fn rpc_timeout(&self) -> Duration {
+        Duration::from_secs(self.rpc_timeout_secs)
+    }
 }
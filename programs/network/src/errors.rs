@@ -19,4 +19,73 @@ pub enum AntegenNetworkError {
 
     #[msg("The worker cannot rotate into the pool right now")]
     PoolFull,
+
+    #[msg("The fee mint and fee price feed must be set together")]
+    InvalidFeeToken,
+
+    #[msg("The worker's stake is below the network minimum")]
+    InsufficientStake,
+
+    #[msg("The stake amount is invalid")]
+    InvalidStakeAmount,
+
+    #[msg("The stake is still unbonding")]
+    StakeUnbonding,
+
+    #[msg("The worker has been deactivated")]
+    WorkerDeactivated,
+
+    #[msg("The worker must be deactivated and fully distributed before it can be closed")]
+    WorkerActive,
+
+    #[msg("The worker's stake must be withdrawn before it can be closed")]
+    StakeNotWithdrawn,
+
+    #[msg("The network fee cannot exceed 10,000 basis points")]
+    InvalidNetworkFee,
+
+    #[msg("Admin changes must be proposed while a timelock is set")]
+    TimelockActive,
+
+    #[msg("The proposal's timelock has not elapsed")]
+    ProposalNotReady,
+
+    #[msg("The accounts required to execute the proposal were not provided")]
+    InvalidProposalAccounts,
+
+    #[msg("The worker stats accounts do not match the pool's workers")]
+    InvalidWorkerStats,
+
+    #[msg("The epoch summary cannot record any more workers")]
+    EpochSummaryFull,
+
+    #[msg("The batch size must be between one and the maximum batch size")]
+    InvalidBatchSize,
+
+    #[msg("The accounts of the batch's workers are invalid")]
+    InvalidBatch,
+
+    #[msg("Network thread intervals must evenly divide a minute, an hour or a day")]
+    InvalidThreadSchedule,
+
+    #[msg("A worker metadata field exceeds its maximum length")]
+    InvalidWorkerMetadata,
+
+    #[msg("The worker has no commission to claim")]
+    NothingToClaim,
+
+    #[msg("The account has already been migrated to its current layout")]
+    AccountMigrated,
+
+    #[msg("Worker fees cannot be paid in tokens until token commissions can be claimed")]
+    TokenFeesUnsupported,
+
+    #[msg("The worker's commission must be distributed and claimed before it can be closed")]
+    CommissionNotClaimed,
+
+    #[msg("Every pool must be provided, in id order")]
+    InvalidPoolAccounts,
+
+    #[msg("The snapshot frame does not belong to the snapshot")]
+    InvalidSnapshotFrame,
 }
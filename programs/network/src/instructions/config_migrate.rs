@@ -0,0 +1,42 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{prelude::*, solana_program::system_program, Discriminator},
+    std::mem::size_of,
+};
+
+use super::pool_migrate::{read_legacy_account, write_migrated_account};
+
+#[derive(Accounts)]
+pub struct ConfigMigrate<'info> {
+    /// CHECK: The config is read with its legacy layout, since it cannot be deserialized until it is migrated.
+    #[account(
+        mut,
+        address = Config::pubkey(),
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigMigrate>) -> Result<()> {
+    // Get accounts
+    let config: &UncheckedAccount = &ctx.accounts.config;
+    let payer: &Signer = &ctx.accounts.payer;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+
+    // Verify the config has not been migrated yet.
+    let data_len: usize = 8 + size_of::<Config>();
+    require!(
+        config.data_len().lt(&data_len),
+        AntegenNetworkError::AccountMigrated
+    );
+
+    // Migrate the config, filling the appended settings with their defaults.
+    let legacy_config: LegacyConfig = read_legacy_account(config, Config::DISCRIMINATOR)?;
+    write_migrated_account(config, payer, system_program, data_len, &Config::from(legacy_config))
+}
@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(Accounts)]
 #[instruction(settings: ConfigSettings)]
@@ -9,7 +12,8 @@ pub struct ConfigUpdate<'info> {
     #[account(
         mut,
         address = Config::pubkey(),
-        has_one = admin
+        has_one = admin,
+        constraint = config.timelock.eq(&0) @ AntegenNetworkError::TimelockActive
     )]
     pub config: Account<'info, Config>,
 }
@@ -0,0 +1,44 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct DelegationClaim<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = delegation.pubkey(),
+        has_one = authority,
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+pub fn handler(ctx: Context<DelegationClaim>) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let delegation: &mut Account<Delegation> = &mut ctx.accounts.delegation;
+
+    require!(
+        delegation.yield_balance.gt(&0),
+        AntegenNetworkError::InvalidStakeAmount
+    );
+
+    // Pay the earned fees to the authority.
+    let amount: u64 = delegation.yield_balance;
+    delegation.yield_balance = 0;
+    **delegation.to_account_info().try_borrow_mut_lamports()? = delegation
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .unwrap();
+    **authority.to_account_info().try_borrow_mut_lamports()? = authority
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{prelude::*, solana_program::system_program},
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+pub struct DelegationCreate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            SEED_DELEGATION,
+            worker.key().as_ref(),
+            worker.total_delegations.to_be_bytes().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<Delegation>(),
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        address = worker.pubkey(),
+        constraint = worker.is_active() @ AntegenNetworkError::WorkerDeactivated,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<DelegationCreate>) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let delegation: &mut Account<Delegation> = &mut ctx.accounts.delegation;
+    let worker: &mut Account<Worker> = &mut ctx.accounts.worker;
+
+    // Initialize the delegation account.
+    delegation.init(authority.key(), worker.total_delegations, worker.key())?;
+
+    // Update the worker's delegation counter.
+    worker.total_delegations = worker.total_delegations.checked_add(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DelegationDeposit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = delegation.pubkey(),
+        has_one = authority,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DelegationDeposit>, amount: u64) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let delegation: &mut Account<Delegation> = &mut ctx.accounts.delegation;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+
+    require!(amount.gt(&0), AntegenNetworkError::InvalidStakeAmount);
+
+    // Delegate the lamports.
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: authority.to_account_info(),
+                to: delegation.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    delegation.stake_amount = delegation.stake_amount.checked_add(amount).unwrap();
+
+    Ok(())
+}
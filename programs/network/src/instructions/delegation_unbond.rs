@@ -0,0 +1,34 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DelegationUnbond<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = delegation.pubkey(),
+        has_one = authority,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+}
+
+pub fn handler(ctx: Context<DelegationUnbond>, amount: u64) -> Result<()> {
+    // Get accounts
+    let delegation: &mut Account<Delegation> = &mut ctx.accounts.delegation;
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+
+    // Start the unbonding period. The lamports leave the next snapshot, but earn the current epoch's fees.
+    delegation.unbond(amount, registry.current_epoch)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct DelegationWithdraw<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = delegation.pubkey(),
+        has_one = authority,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+}
+
+pub fn handler(ctx: Context<DelegationWithdraw>) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let delegation: &mut Account<Delegation> = &mut ctx.accounts.delegation;
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+
+    // Verify there are lamports to withdraw and the epoch they were snapshotted for has closed.
+    require!(
+        delegation.unbonding_amount.gt(&0),
+        AntegenNetworkError::InvalidStakeAmount
+    );
+    require!(
+        delegation.is_unbonded(registry.current_epoch),
+        AntegenNetworkError::StakeUnbonding
+    );
+
+    // Return the unbonded lamports to the authority.
+    let amount: u64 = delegation.unbonding_amount;
+    delegation.unbonding_amount = 0;
+    **delegation.to_account_info().try_borrow_mut_lamports()? = delegation
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .unwrap();
+    **authority.to_account_info().try_borrow_mut_lamports()? = authority
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .unwrap();
+
+    Ok(())
+}
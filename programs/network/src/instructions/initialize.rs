@@ -57,7 +57,7 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     let snapshot: &mut Account<Snapshot> = &mut ctx.accounts.snapshot;
 
     // Initialize accounts.
-    config.init(admin)?;
+    config.init(admin, ANTEGEN_SQUADS)?;
     registry.init()?;
     snapshot.init(0)?;
 
@@ -1,21 +1,61 @@
+pub mod config_migrate;
 pub mod config_update;
+pub mod delegation_claim;
+pub mod delegation_create;
+pub mod delegation_deposit;
+pub mod delegation_unbond;
+pub mod delegation_withdraw;
 pub mod initialize;
 pub mod pool_create;
+pub mod pool_migrate;
 pub mod pool_rotate;
 pub mod pool_update;
+pub mod proposal_cancel;
+pub mod proposal_create;
+pub mod proposal_execute;
 pub mod registry_nonce_hash;
 pub mod registry_unlock;
 pub mod registry_reset;
+pub mod snapshot_migrate;
+pub mod worker_claim;
+pub mod worker_close;
 pub mod worker_create;
+pub mod worker_deactivate;
+pub mod worker_heartbeat;
+pub mod worker_migrate;
+pub mod worker_stake_deposit;
+pub mod worker_stake_unbond;
+pub mod worker_stake_withdraw;
+pub mod worker_stats_record;
 pub mod worker_update;
 
+pub use config_migrate::*;
 pub use config_update::*;
+pub use delegation_claim::*;
+pub use delegation_create::*;
+pub use delegation_deposit::*;
+pub use delegation_unbond::*;
+pub use delegation_withdraw::*;
 pub use initialize::*;
 pub use pool_create::*;
+pub use pool_migrate::*;
 pub use pool_rotate::*;
 pub use pool_update::*;
+pub use proposal_cancel::*;
+pub use proposal_create::*;
+pub use proposal_execute::*;
 pub use registry_nonce_hash::*;
 pub use registry_unlock::*;
 pub use registry_reset::*;
+pub use snapshot_migrate::*;
+pub use worker_claim::*;
+pub use worker_close::*;
 pub use worker_create::*;
+pub use worker_deactivate::*;
+pub use worker_heartbeat::*;
+pub use worker_migrate::*;
+pub use worker_stake_deposit::*;
+pub use worker_stake_unbond::*;
+pub use worker_stake_withdraw::*;
+pub use worker_stats_record::*;
 pub use worker_update::*;
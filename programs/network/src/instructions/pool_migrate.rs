@@ -0,0 +1,92 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+        Discriminator,
+    },
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct PoolMigrate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The pool is read with its legacy layout, since it cannot be deserialized until it is migrated.
+    #[account(
+        mut,
+        address = Pool::pubkey(id),
+        owner = crate::ID,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PoolMigrate>, _id: u64) -> Result<()> {
+    // Get accounts
+    let payer: &Signer = &ctx.accounts.payer;
+    let pool: &UncheckedAccount = &ctx.accounts.pool;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+
+    // Read the pool with its legacy layout.
+    let legacy_pool: LegacyPool = read_legacy_account(pool, Pool::DISCRIMINATOR)?;
+
+    // Verify the pool has not been migrated yet.
+    let data_len: usize = 8 + size_of::<Pool>() + (legacy_pool.size as usize).checked_mul(size_of::<Pubkey>()).unwrap();
+    require!(
+        pool.data_len().lt(&data_len),
+        AntegenNetworkError::AccountMigrated
+    );
+
+    write_migrated_account(pool, payer, system_program, data_len, &Pool::from(legacy_pool))
+}
+
+/// Reads an account's legacy layout, after verifying its discriminator.
+pub(crate) fn read_legacy_account<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: &[u8],
+) -> Result<T> {
+    let data = account.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    T::deserialize(&mut &data[discriminator.len()..])
+        .map_err(|_err| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Resizes an account for its new layout, topping up its rent from the payer, and writes the migrated data.
+pub(crate) fn write_migrated_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    data_len: usize,
+    migrated: &T,
+) -> Result<()> {
+    // Reallocate memory for the new layout
+    account.realloc(data_len, true)?;
+
+    // If lamports are required to maintain rent-exemption, pay them
+    let minimum_rent: u64 = Rent::get().unwrap().minimum_balance(data_len);
+    if minimum_rent > account.lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            minimum_rent.checked_sub(account.lamports()).unwrap(),
+        )?;
+    }
+
+    // Write the account with its new layout
+    let mut data = account.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])
+}
@@ -1,6 +1,7 @@
 use {
     crate::{errors::*, state::*},
-    anchor_lang::prelude::*,
+    anchor_lang::{prelude::*, solana_program::system_program},
+    std::mem::size_of,
 };
 
 #[derive(Accounts)]
@@ -18,25 +19,53 @@ pub struct PoolRotate<'info> {
     )]
     pub pool: Account<'info, Pool>,
 
+    #[account(address = Registry::pubkey())]
+    pub registry: Account<'info, Registry>,
+
     #[account(mut)]
     pub signatory: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STAKE,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = signatory,
+        space = 8 + size_of::<WorkerStake>(),
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
     #[account(
         address = worker.pubkey(),
-        has_one = signatory
+        has_one = signatory,
+        constraint = worker.is_active() @ AntegenNetworkError::WorkerDeactivated,
     )]
     pub worker: Account<'info, Worker>,
 }
 
 pub fn handler(ctx: Context<PoolRotate>) -> Result<()> {
     // Get accounts
+    let clock = Clock::get().unwrap();
+    let config = &ctx.accounts.config;
     let pool = &mut ctx.accounts.pool;
+    let registry = &ctx.accounts.registry;
+    let stake = &mut ctx.accounts.stake;
     let worker = &ctx.accounts.worker;
 
-    // Verify the pool has excess space.
+    // Initialize the stake account of workers registered before staking existed.
+    if !stake.is_initialized() {
+        stake.init(worker.key())?;
+    }
+
+    // Verify the worker has bonded enough stake to be eligible for the pool.
     require!(
-        pool.workers.len() < (pool.size as usize),
-        AntegenNetworkError::PoolFull
+        stake.amount.ge(&config.min_stake),
+        AntegenNetworkError::InsufficientStake
     );
 
     // Verify the worker is not already in the pool.
@@ -45,8 +74,19 @@ pub fn handler(ctx: Context<PoolRotate>) -> Result<()> {
         AntegenNetworkError::AlreadyInPool
     );
 
-    // Rotate the worker into the pool.
-    pool.rotate(worker.key())?;
+    // If the pool is full, only the worker selected by the registry nonce may take a seat,
+    // and only once the rotation period has elapsed.
+    if !pool.has_space() {
+        require!(
+            pool.size.gt(&0)
+                && pool.is_rotation_due(clock.slot)
+                && registry.is_selected(worker.id),
+            AntegenNetworkError::PoolFull
+        );
+    }
+
+    // Rotate the worker into the pool, evicting the longest-seated worker if full.
+    pool.rotate(worker.key(), clock.slot)?;
 
     Ok(())
 }
@@ -1,5 +1,5 @@
 use {
-    crate::state::*,
+    crate::{errors::*, state::*},
     anchor_lang::{
         prelude::*,
         solana_program::system_program,
@@ -16,7 +16,8 @@ pub struct PoolUpdate<'info> {
 
     #[account(
         address = Config::pubkey(), 
-        has_one = admin
+        has_one = admin,
+        constraint = config.timelock.eq(&0) @ AntegenNetworkError::TimelockActive
     )]
     pub config: Account<'info, Config>,
 
@@ -36,8 +37,18 @@ pub fn handler(ctx: Context<PoolUpdate>, settings: PoolSettings) -> Result<()> {
     let pool: &mut Account<Pool> = &mut ctx.accounts.pool;
     let system_program: &Program<System> = &ctx.accounts.system_program;
 
+    update_pool(pool, admin, system_program, &settings)
+}
+
+/// Applies new settings to a pool, resizing the account and topping up its rent from the payer.
+pub fn update_pool<'info>(
+    pool: &mut Account<'info, Pool>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    settings: &PoolSettings,
+) -> Result<()> {
     // Update the pool settings
-    pool.update(&settings)?;
+    pool.update(settings)?;
 
     // Reallocate memory for the pool account
     let data_len: usize = 8 + size_of::<Pool>() + (settings.size as usize).checked_mul(size_of::<Pubkey>()).unwrap();
@@ -50,7 +61,7 @@ pub fn handler(ctx: Context<PoolUpdate>, settings: PoolSettings) -> Result<()> {
             CpiContext::new(
                 system_program.to_account_info(),
                 Transfer {
-                    from: admin.to_account_info(),
+                    from: payer.to_account_info(),
                     to: pool.to_account_info(),
                 },
             ),
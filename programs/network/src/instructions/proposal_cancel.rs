@@ -0,0 +1,27 @@
+use {crate::state::*, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct ProposalCancel<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        address = Config::pubkey(),
+        has_one = admin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = proposal.pubkey(),
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<ProposalCancel>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use {
+    crate::state::*,
+    anchor_lang::{prelude::*, solana_program::system_program},
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+#[instruction(action: ProposalAction)]
+pub struct ProposalCreate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        address = Config::pubkey(),
+        has_one = admin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        seeds = [
+            SEED_PROPOSAL,
+            config.total_proposals.to_be_bytes().as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = 8 + size_of::<Proposal>(),
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposalCreate>, action: ProposalAction) -> Result<()> {
+    // Get accounts
+    let admin: &Signer = &ctx.accounts.admin;
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    let proposal: &mut Account<Proposal> = &mut ctx.accounts.proposal;
+
+    // Initialize the proposal, executable once the timelock elapses.
+    let executable_at: u64 = Clock::get()
+        .unwrap()
+        .slot
+        .checked_add(config.timelock)
+        .unwrap();
+    proposal.init(action, executable_at, config.total_proposals, admin.key())?;
+
+    // Update the config's proposal counter.
+    config.total_proposals = config.total_proposals.checked_add(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use {
+    crate::{
+        errors::*,
+        instructions::{pool_update::update_pool, worker_deactivate::leave_pools},
+        state::*,
+    },
+    anchor_lang::{prelude::*, solana_program::system_program},
+    std::mem::size_of,
+};
+
+/// Worker slash proposals take every pool as remaining accounts, in id order, to evict the worker from.
+#[derive(Accounts)]
+pub struct ProposalExecute<'info> {
+    #[account(
+        mut,
+        address = Config::pubkey()
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The network fee recipient, required by worker slash proposals.
+    #[account(
+        mut,
+        address = config.network_fee_recipient
+    )]
+    pub network_fee: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The pool to update, required by pool update proposals.
+    #[account(mut)]
+    pub pool: Option<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        address = proposal.pubkey(),
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    /// The registry, required by registry reset, registry unlock and worker slash proposals.
+    #[account(
+        mut,
+        address = Registry::pubkey()
+    )]
+    pub registry: Option<Account<'info, Registry>>,
+
+    /// The zeroth snapshot, required by registry reset proposals.
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_SNAPSHOT,
+            (0 as u64).to_be_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<Snapshot>(),
+    )]
+    pub snapshot: Option<Account<'info, Snapshot>>,
+
+    /// The slashed worker's stake, required by worker slash proposals.
+    #[account(
+        mut,
+        address = WorkerStake::pubkey(stake.worker)
+    )]
+    pub stake: Option<Account<'info, WorkerStake>>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    /// The slashed worker, required by worker slash proposals.
+    pub worker: Option<Account<'info, Worker>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ProposalExecute<'info>>) -> Result<()> {
+    // Get accounts
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    let payer: &Signer = &ctx.accounts.payer;
+    let proposal: &Account<Proposal> = &ctx.accounts.proposal;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+
+    // Verify the timelock has elapsed.
+    require!(
+        Clock::get().unwrap().slot.ge(&proposal.executable_at),
+        AntegenNetworkError::ProposalNotReady
+    );
+
+    // Apply the proposed change.
+    match proposal.action.clone() {
+        ProposalAction::ConfigUpdate { settings } => config.update(settings)?,
+        ProposalAction::PoolUpdate { pool_id, settings } => {
+            let pool = ctx
+                .accounts
+                .pool
+                .as_mut()
+                .filter(|pool| pool.id.eq(&pool_id))
+                .ok_or(AntegenNetworkError::InvalidProposalAccounts)?;
+            update_pool(pool, payer, system_program, &settings)?;
+        }
+        ProposalAction::RegistryReset => {
+            let (Some(registry), Some(snapshot)) =
+                (ctx.accounts.registry.as_mut(), ctx.accounts.snapshot.as_mut())
+            else {
+                return Err(AntegenNetworkError::InvalidProposalAccounts.into());
+            };
+            registry.reset()?;
+            snapshot.init(0)?;
+        }
+        ProposalAction::RegistryUnlock => {
+            let registry = ctx
+                .accounts
+                .registry
+                .as_mut()
+                .ok_or(AntegenNetworkError::InvalidProposalAccounts)?;
+            registry.locked = false;
+        }
+        ProposalAction::WorkerSlash { worker, amount } => {
+            let (Some(network_fee), Some(registry), Some(stake), Some(worker)) = (
+                ctx.accounts.network_fee.as_ref(),
+                ctx.accounts.registry.as_ref(),
+                ctx.accounts.stake.as_mut(),
+                ctx.accounts.worker.as_ref().filter(|w| w.key().eq(&worker)),
+            ) else {
+                return Err(AntegenNetworkError::InvalidProposalAccounts.into());
+            };
+            require!(
+                stake.worker.eq(&worker.key()),
+                AntegenNetworkError::InvalidProposalAccounts
+            );
+            slash_worker(network_fee, stake, amount)?;
+
+            // Evict the worker from every pool if its stake no longer meets the minimum.
+            if stake.amount.lt(&config.min_stake) {
+                leave_pools(registry, ctx.remaining_accounts, worker.key())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Slashes a worker's stake, sending the lamports to the network.
+fn slash_worker(network_fee: &AccountInfo, stake: &mut Account<WorkerStake>, amount: u64) -> Result<()> {
+    stake.slash(amount)?;
+    **stake.to_account_info().try_borrow_mut_lamports()? = stake
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .unwrap();
+    **network_fee.to_account_info().try_borrow_mut_lamports()? = network_fee
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .unwrap();
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use {
-  crate::state::*,
+  crate::{errors::*, state::*},
   anchor_lang::{prelude::*, solana_program::system_program},
 };
 use std::mem::size_of;
@@ -11,7 +11,8 @@ pub struct RegistryReset<'info> {
 
   #[account(
       address = Config::pubkey(), 
-      has_one = admin
+      has_one = admin,
+      constraint = config.timelock.eq(&0) @ AntegenNetworkError::TimelockActive
   )]
   pub config: Account<'info, Config>,
 
@@ -1,4 +1,7 @@
-use {crate::state::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
 
 #[derive(Accounts)]
 pub struct RegistryUnlock<'info> {
@@ -7,7 +10,8 @@ pub struct RegistryUnlock<'info> {
 
     #[account(
         address = Config::pubkey(),
-        has_one = admin
+        has_one = admin,
+        constraint = config.timelock.eq(&0) @ AntegenNetworkError::TimelockActive
     )]
     pub config: Account<'info, Config>,
 
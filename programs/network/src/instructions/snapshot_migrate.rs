@@ -0,0 +1,67 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{prelude::*, solana_program::system_program, Discriminator},
+    std::mem::size_of,
+};
+
+use super::pool_migrate::{read_legacy_account, write_migrated_account};
+
+/// The remaining accounts hold frames of the snapshot to migrate. Frames already migrated are skipped.
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct SnapshotMigrate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The snapshot is read with its legacy layout, since it cannot be deserialized until it is migrated.
+    #[account(
+        mut,
+        address = Snapshot::pubkey(id),
+        owner = crate::ID,
+    )]
+    pub snapshot: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SnapshotMigrate<'info>>, _id: u64) -> Result<()> {
+    // Get accounts
+    let payer: &Signer = &ctx.accounts.payer;
+    let snapshot: &UncheckedAccount = &ctx.accounts.snapshot;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+
+    // Migrate the snapshot if it is still on its legacy layout, resuming worker visits after its last frame.
+    let snapshot_data_len: usize = 8 + size_of::<Snapshot>();
+    let is_snapshot_legacy = snapshot.data_len().lt(&snapshot_data_len);
+    if is_snapshot_legacy {
+        let legacy_snapshot: LegacySnapshot = read_legacy_account(snapshot, Snapshot::DISCRIMINATOR)?;
+        write_migrated_account(snapshot, payer, system_program, snapshot_data_len, &Snapshot::from(legacy_snapshot))?;
+    }
+
+    // Migrate the snapshot's legacy frames.
+    let frame_data_len: usize = 8 + size_of::<SnapshotFrame>();
+    let mut total_migrated_frames: usize = 0;
+    for frame in ctx.remaining_accounts.iter() {
+        if frame.data_len().ge(&frame_data_len) {
+            continue;
+        }
+        require!(frame.owner.eq(&crate::ID), AntegenNetworkError::InvalidSnapshotFrame);
+        let legacy_frame: LegacySnapshotFrame = read_legacy_account(frame, SnapshotFrame::DISCRIMINATOR)?;
+        require!(
+            legacy_frame.snapshot.eq(&snapshot.key())
+                && frame.key().eq(&SnapshotFrame::pubkey(snapshot.key(), legacy_frame.id)),
+            AntegenNetworkError::InvalidSnapshotFrame
+        );
+        write_migrated_account(frame, payer, system_program, frame_data_len, &SnapshotFrame::from(legacy_frame))?;
+        total_migrated_frames = total_migrated_frames.checked_add(1).unwrap();
+    }
+
+    // Verify there was something to migrate.
+    require!(
+        is_snapshot_legacy || total_migrated_frames.gt(&0),
+        AntegenNetworkError::AccountMigrated
+    );
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct WorkerClaim<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_WORKER_COMMISSION,
+            commission.worker.as_ref(),
+        ],
+        bump,
+        has_one = worker,
+    )]
+    pub commission: Account<'info, WorkerCommission>,
+
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.network_fee_recipient
+    )]
+    pub network_fee: SystemAccount<'info>,
+
+    #[account(address = Registry::pubkey())]
+    pub registry: Account<'info, Registry>,
+
+    /// The worker's frame in the current snapshot, whose stake decides whether it earns a commission.
+    /// Workers without one, such as those registered this epoch, earn no commission.
+    #[account(
+        address = snapshot_frame.pubkey(),
+        has_one = worker,
+        constraint = snapshot_frame.snapshot.eq(&Snapshot::pubkey(registry.current_epoch)) @ AntegenNetworkError::InvalidSnapshotFrame,
+    )]
+    pub snapshot_frame: Option<Account<'info, SnapshotFrame>>,
+
+    /// Deactivated workers are paid out by their final fee distribution, so their commission can be closed.
+    #[account(
+        address = worker.pubkey(),
+        has_one = authority,
+        constraint = worker.is_active() @ AntegenNetworkError::WorkerDeactivated,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerClaim>) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let commission: &mut Account<WorkerCommission> = &mut ctx.accounts.commission;
+    let config: &Account<Config> = &ctx.accounts.config;
+    let network_fee: &mut SystemAccount = &mut ctx.accounts.network_fee;
+    let snapshot_frame: &Option<Account<SnapshotFrame>> = &ctx.accounts.snapshot_frame;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    // Calculate the fees collected since the last split, excluding the balances owed to delegators.
+    let commission_lamport_balance: u64 = commission.to_account_info().lamports();
+    let commission_data_len: usize = 8 + commission.try_to_vec()?.len();
+    let commission_rent_balance: u64 = Rent::get().unwrap().minimum_balance(commission_data_len);
+    let commission_usable_balance: u64 = commission_lamport_balance
+        .saturating_sub(commission_rent_balance)
+        .saturating_sub(commission.delegator_balance)
+        .saturating_sub(commission.distributable_balance);
+    require!(
+        commission_usable_balance.gt(&0),
+        AntegenNetworkError::NothingToClaim
+    );
+
+    // Split the fees the same way the epoch's fee distribution does, against the worker's snapshotted stake.
+    let snapshot_stake: u64 = snapshot_frame.as_ref().map_or(0, |frame| frame.stake);
+    let commission_bps: u64 = config.commission_bps(worker.commission_rate, snapshot_stake);
+    let (network_fee_balance, commission_balance, delegator_balance) =
+        split_fees(commission_usable_balance, config.network_fee_bps, commission_bps);
+
+    // Hold the delegators' share until the next fee distribution pays it out.
+    commission.delegator_balance = commission.delegator_balance.checked_add(delegator_balance).unwrap();
+
+    // Transfer the commission to the authority.
+    if commission_balance.gt(&0) {
+        **commission.to_account_info().try_borrow_mut_lamports()? = commission
+            .to_account_info()
+            .lamports()
+            .checked_sub(commission_balance)
+            .unwrap();
+        **authority.to_account_info().try_borrow_mut_lamports()? = authority
+            .to_account_info()
+            .lamports()
+            .checked_add(commission_balance)
+            .unwrap();
+    }
+
+    // Transfer the network's fee.
+    if network_fee_balance.gt(&0) {
+        **commission.to_account_info().try_borrow_mut_lamports()? = commission
+            .to_account_info()
+            .lamports()
+            .checked_sub(network_fee_balance)
+            .unwrap();
+        **network_fee.to_account_info().try_borrow_mut_lamports()? = network_fee
+            .to_account_info()
+            .lamports()
+            .checked_add(network_fee_balance)
+            .unwrap();
+    }
+
+    Ok(())
+}
+
+/// Splits fees into the network's fee, the worker's commission and the delegators' share.
+/// The commission is taken from what remains after the network's fee.
+pub(crate) fn split_fees(usable_balance: u64, network_fee_bps: u64, commission_bps: u64) -> (u64, u64, u64) {
+    let network_fee_balance: u64 = usable_balance
+        .checked_mul(network_fee_bps)
+        .unwrap()
+        .checked_div(TOTAL_BASIS_POINTS)
+        .unwrap();
+    let worker_usable_balance: u64 = usable_balance.checked_sub(network_fee_balance).unwrap();
+    let commission_balance: u64 = worker_usable_balance
+        .checked_mul(commission_bps)
+        .unwrap()
+        .checked_div(TOTAL_BASIS_POINTS)
+        .unwrap();
+    let delegator_balance: u64 = worker_usable_balance.checked_sub(commission_balance).unwrap();
+    (network_fee_balance, commission_balance, delegator_balance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_fees_takes_network_fee_first() {
+        // 10% to the network, then 50% of the rest to the worker.
+        assert_eq!(split_fees(1_000, 1_000, 5_000), (100, 450, 450));
+    }
+
+    #[test]
+    fn split_fees_without_commission() {
+        assert_eq!(split_fees(1_000, 1_000, 0), (100, 0, 900));
+    }
+
+    #[test]
+    fn split_fees_leaves_rounding_to_delegators() {
+        let (network_fee_balance, commission_balance, delegator_balance) = split_fees(999, 333, 3_333);
+        assert_eq!((network_fee_balance, commission_balance, delegator_balance), (33, 321, 645));
+        assert_eq!(network_fee_balance + commission_balance + delegator_balance, 999);
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct WorkerClose<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_WORKER_COMMISSION,
+            worker.key().as_ref(),
+        ],
+        bump,
+        has_one = worker,
+        constraint = commission.distributable_balance.eq(&0) && commission.delegator_balance.eq(&0) @ AntegenNetworkError::CommissionNotClaimed,
+        close = authority,
+    )]
+    pub commission: Account<'info, WorkerCommission>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        address = stake.pubkey(),
+        has_one = worker,
+        constraint = stake.amount.eq(&0) && stake.unbonding_amount.eq(&0) @ AntegenNetworkError::StakeNotWithdrawn,
+        close = authority,
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    /// The worker's statistics, if the account was ever created.
+    #[account(
+        mut,
+        address = WorkerStats::pubkey(worker.key()),
+        close = authority,
+    )]
+    pub stats: Option<Account<'info, WorkerStats>>,
+
+    #[account(
+        mut,
+        address = worker.pubkey(),
+        has_one = authority,
+        close = authority,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerClose>) -> Result<()> {
+    // Get accounts
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    // The worker's last snapshot frame is distributed when the epoch it was deactivated in ends.
+    // Only then may its accounts be closed, returning the remaining commission and rent to the authority.
+    require!(
+        worker
+            .deactivated_at
+            .is_some_and(|deactivated_at| deactivated_at.lt(&registry.current_epoch)),
+        AntegenNetworkError::WorkerActive
+    );
+
+    Ok(())
+}
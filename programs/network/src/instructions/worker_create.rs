@@ -39,6 +39,30 @@ pub struct WorkerCreate<'info> {
     )]
     pub commission: Account<'info, WorkerCommission>,
 
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STAKE,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<WorkerStake>(),
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STATS,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<WorkerStats>(),
+    )]
+    pub stats: Account<'info, WorkerStats>,
+
     #[account(
         mut, 
         address = Registry::pubkey(),
@@ -56,11 +80,15 @@ pub fn handler(ctx: Context<WorkerCreate>) -> Result<()> {
     let commission: &mut Account<WorkerCommission> = &mut ctx.accounts.commission;
     let registry: &mut Account<Registry> = &mut ctx.accounts.registry;
     let signatory: &mut Signer = &mut ctx.accounts.signatory;
+    let stake: &mut Account<WorkerStake> = &mut ctx.accounts.stake;
+    let stats: &mut Account<WorkerStats> = &mut ctx.accounts.stats;
     let worker: &mut Account<Worker> = &mut ctx.accounts.worker;
 
     // Initialize the worker accounts.
     worker.init(authority, registry.total_workers, signatory)?;
     commission.init(worker.key())?;
+    stake.init(worker.key())?;
+    stats.init(worker.key())?;
 
     // Update the registry's worker counter.
     registry.total_workers = registry.total_workers.checked_add(1).unwrap();
@@ -0,0 +1,60 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+/// The remaining accounts hold every pool, in id order, so the worker leaves all of them.
+#[derive(Accounts)]
+pub struct WorkerDeactivate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked @ AntegenNetworkError::RegistryLocked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        address = worker.pubkey(),
+        has_one = authority,
+        constraint = worker.is_active() @ AntegenNetworkError::WorkerDeactivated,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WorkerDeactivate<'info>>) -> Result<()> {
+    // Get accounts
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+    let worker: &mut Account<Worker> = &mut ctx.accounts.worker;
+
+    // Leave every pool, so the worker is no longer assigned threads anywhere.
+    leave_pools(registry, ctx.remaining_accounts, worker.key())?;
+
+    // Stop the worker from being snapshotted from the next epoch onwards.
+    worker.deactivated_at = Some(registry.current_epoch);
+
+    Ok(())
+}
+
+/// Removes a worker from every pool. The accounts must hold every pool, in id order.
+pub fn leave_pools<'info>(
+    registry: &Registry,
+    pools: &'info [AccountInfo<'info>],
+    worker: Pubkey,
+) -> Result<()> {
+    require!(
+        (pools.len() as u64).eq(&registry.total_pools),
+        AntegenNetworkError::InvalidPoolAccounts
+    );
+    for (id, account) in pools.iter().enumerate() {
+        let mut pool: Account<Pool> = Account::try_from(account)?;
+        require!(
+            pool.key().eq(&Pool::pubkey(id as u64)),
+            AntegenNetworkError::InvalidPoolAccounts
+        );
+        pool.workers.retain(|w| w.ne(&worker));
+        pool.exit(&crate::ID)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use {
+    crate::state::*,
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+    },
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+pub struct WorkerHeartbeat<'info> {
+    #[account(mut)]
+    pub signatory: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STATS,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = signatory,
+        space = 8 + size_of::<WorkerStats>(),
+    )]
+    pub stats: Account<'info, WorkerStats>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        address = worker.pubkey(),
+        has_one = signatory,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerHeartbeat>) -> Result<()> {
+    // Get accounts
+    let stats: &mut Account<WorkerStats> = &mut ctx.accounts.stats;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    // Initialize the stats account of workers registered before statistics existed.
+    if !stats.is_initialized() {
+        stats.init(worker.key())?;
+    }
+
+    // Record the worker as live at the current slot.
+    stats.heartbeat(Clock::get()?.slot)?;
+
+    Ok(())
+}
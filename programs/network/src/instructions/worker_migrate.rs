@@ -0,0 +1,68 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{prelude::*, solana_program::system_program, Discriminator},
+    std::mem::size_of,
+};
+
+use super::pool_migrate::{read_legacy_account, write_migrated_account};
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct WorkerMigrate<'info> {
+    /// CHECK: The commission is read with its legacy layout, since it cannot be deserialized until it is migrated.
+    #[account(
+        mut,
+        address = WorkerCommission::pubkey(worker.key()),
+        owner = crate::ID,
+    )]
+    pub commission: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The worker is read with its legacy layout, since it cannot be deserialized until it is migrated.
+    #[account(
+        mut,
+        address = Worker::pubkey(id),
+        owner = crate::ID,
+    )]
+    pub worker: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WorkerMigrate>, _id: u64) -> Result<()> {
+    // Get accounts
+    let commission: &UncheckedAccount = &ctx.accounts.commission;
+    let payer: &Signer = &ctx.accounts.payer;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+    let worker: &UncheckedAccount = &ctx.accounts.worker;
+
+    // Migrate each account still on its legacy layout. Current workers never shrink to the legacy size.
+    let worker_data_len: usize = 8 + size_of::<Worker>();
+    let commission_data_len: usize = 8 + size_of::<WorkerCommission>();
+    let is_worker_legacy = worker.data_len().le(&(8 + size_of::<LegacyWorker>()));
+    let is_commission_legacy = commission.data_len().lt(&commission_data_len);
+    require!(
+        is_worker_legacy || is_commission_legacy,
+        AntegenNetworkError::AccountMigrated
+    );
+    if is_worker_legacy {
+        let legacy_worker: LegacyWorker = read_legacy_account(worker, Worker::DISCRIMINATOR)?;
+        write_migrated_account(worker, payer, system_program, worker_data_len, &Worker::from(legacy_worker))?;
+    }
+    if is_commission_legacy {
+        let legacy_commission: LegacyWorkerCommission =
+            read_legacy_account(commission, WorkerCommission::DISCRIMINATOR)?;
+        write_migrated_account(
+            commission,
+            payer,
+            system_program,
+            commission_data_len,
+            &WorkerCommission::from(legacy_commission),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+    },
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WorkerStakeDeposit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STAKE,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<WorkerStake>(),
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        address = worker.pubkey(),
+        has_one = authority,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerStakeDeposit>, amount: u64) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let stake: &mut Account<WorkerStake> = &mut ctx.accounts.stake;
+    let system_program: &Program<System> = &ctx.accounts.system_program;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    require!(amount.gt(&0), AntegenNetworkError::InvalidStakeAmount);
+
+    // Initialize the stake account of workers registered before staking existed.
+    if !stake.is_initialized() {
+        stake.init(worker.key())?;
+    }
+
+    // Bond the lamports.
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: authority.to_account_info(),
+                to: stake.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    stake.amount = stake.amount.checked_add(amount).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WorkerStakeUnbond<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = stake.pubkey(),
+        has_one = worker,
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(
+        address = worker.pubkey(),
+        has_one = authority,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerStakeUnbond>, amount: u64) -> Result<()> {
+    // Get accounts
+    let clock = Clock::get().unwrap();
+    let config: &Account<Config> = &ctx.accounts.config;
+    let stake: &mut Account<WorkerStake> = &mut ctx.accounts.stake;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    // Start the unbonding period. Unbonding more lamports restarts the period.
+    stake.unbond(amount, clock.slot)?;
+
+    // Active workers may hold pool seats, so they must keep the network minimum bonded.
+    // Workers leave their pools when deactivated, after which the rest of the stake may be unbonded.
+    require!(
+        !worker.is_active() || stake.amount.ge(&config.min_stake),
+        AntegenNetworkError::InsufficientStake
+    );
+
+    Ok(())
+}
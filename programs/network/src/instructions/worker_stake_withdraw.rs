@@ -0,0 +1,60 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct WorkerStakeWithdraw<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = stake.pubkey(),
+        has_one = worker,
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(
+        address = worker.pubkey(),
+        has_one = authority,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerStakeWithdraw>) -> Result<()> {
+    // Get accounts
+    let authority: &Signer = &ctx.accounts.authority;
+    let clock = Clock::get().unwrap();
+    let config: &Account<Config> = &ctx.accounts.config;
+    let stake: &mut Account<WorkerStake> = &mut ctx.accounts.stake;
+
+    // Verify there are lamports to withdraw and the unbonding period has elapsed.
+    require!(
+        stake.unbonding_amount.gt(&0),
+        AntegenNetworkError::InvalidStakeAmount
+    );
+    require!(
+        stake.is_unbonded(clock.slot, config.unbonding_period),
+        AntegenNetworkError::StakeUnbonding
+    );
+
+    // Return the unbonded lamports to the authority.
+    let amount: u64 = stake.unbonding_amount;
+    stake.unbonding_amount = 0;
+    **stake.to_account_info().try_borrow_mut_lamports()? = stake
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .unwrap();
+    **authority.to_account_info().try_borrow_mut_lamports()? = authority
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use {
+    crate::{state::*, thread_stats_authority},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+    },
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+pub struct WorkerStatsRecord<'info> {
+    #[account(address = Registry::pubkey())]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut)]
+    pub signatory: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STATS,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = signatory,
+        space = 8 + size_of::<WorkerStats>(),
+    )]
+    pub stats: Account<'info, WorkerStats>,
+
+    /// The thread program's stats authority. Only the thread program's own fee path signs for it.
+    #[account(address = thread_stats_authority())]
+    pub stats_authority: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        address = worker.pubkey(),
+        has_one = signatory,
+    )]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<WorkerStatsRecord>, activity: WorkerActivity) -> Result<()> {
+    // Get accounts
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+    let stats: &mut Account<WorkerStats> = &mut ctx.accounts.stats;
+    let worker: &Account<Worker> = &ctx.accounts.worker;
+
+    // Initialize the stats account of workers registered before statistics existed.
+    if !stats.is_initialized() {
+        stats.init(worker.key())?;
+    }
+
+    // Record the activity against the current epoch.
+    stats.record(registry.current_epoch, activity)?;
+
+    Ok(())
+}
@@ -13,6 +13,9 @@ pub struct WorkerUpdate<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [
@@ -31,11 +34,12 @@ pub struct WorkerUpdate<'info> {
 pub fn handler(ctx: Context<WorkerUpdate>, settings: WorkerSettings) -> Result<()> {
     // Get accounts
     let authority: &Signer = &ctx.accounts.authority;
+    let config: &Account<Config> = &ctx.accounts.config;
     let worker: &mut Account<Worker> = &mut ctx.accounts.worker;
     let system_program: &Program<System> = &ctx.accounts.system_program;
 
     // Update the worker
-    worker.update(settings)?;
+    worker.update(config, settings)?;
 
     // Realloc memory for the worker account
     let data_len: usize = 8 + worker.try_to_vec()?.len();
@@ -1,5 +1,5 @@
 use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
-use antegen_utils::thread::ThreadResponse;
+use antegen_utils::thread::{SerializableInstruction, ThreadResponse};
 
 use crate::state::*;
 
@@ -8,6 +8,9 @@ pub struct DeleteSnapshotJob<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(
         address = Registry::pubkey(),
         constraint = !registry.locked
@@ -20,24 +23,57 @@ pub struct DeleteSnapshotJob<'info> {
 
 pub fn handler(ctx: Context<DeleteSnapshotJob>) -> Result<ThreadResponse> {
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &ctx.accounts.registry;
     let thread = &mut ctx.accounts.thread;
 
+    // Skip the job if it has already run for this epoch.
+    if !epoch_job.enter(EpochPhase::DeleteSnapshot, registry.current_epoch)? {
+        return Ok(ThreadResponse::default());
+    }
+
+    // Delete the oldest snapshot which has fallen out of the retention window, if any.
+    let dynamic_instruction = build_snapshot_instruction(config, epoch_job, registry, thread);
+    if dynamic_instruction.is_none() {
+        epoch_job.complete()?;
+    }
+
     Ok(ThreadResponse {
-        dynamic_instruction: Some(
-            Instruction {
-                program_id: crate::ID,
-                accounts: crate::accounts::DeleteSnapshotProcessSnapshot {
-                    config: config.key(),
-                    registry: registry.key(),
-                    snapshot: Snapshot::pubkey(registry.current_epoch.checked_sub(1).unwrap()),
-                    thread: thread.key(),
-                }
-                .to_account_metas(Some(true)),
-                data: crate::instruction::DeleteSnapshotProcessSnapshot {}.data(),
-            }
-            .into(),
-        ),
+        dynamic_instruction,
         ..ThreadResponse::default()
     })
 }
+
+/// Builds the instruction deleting the oldest snapshot kept beyond the network's retention count, if there is one.
+pub fn build_snapshot_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    thread: &Signer,
+) -> Option<SerializableInstruction> {
+    let retained_epochs = config.snapshot_retention.checked_add(1).unwrap();
+    if epoch_job
+        .next_snapshot
+        .checked_add(retained_epochs)
+        .unwrap()
+        .gt(&registry.current_epoch)
+    {
+        return None;
+    }
+
+    Some(
+        Instruction {
+            program_id: crate::ID,
+            accounts: crate::accounts::DeleteSnapshotProcessSnapshot {
+                config: config.key(),
+                epoch_job: epoch_job.key(),
+                registry: registry.key(),
+                snapshot: Snapshot::pubkey(epoch_job.next_snapshot),
+                thread: thread.key(),
+            }
+            .to_account_metas(Some(true)),
+            data: crate::instruction::DeleteSnapshotProcessSnapshot {}.data(),
+        }
+        .into(),
+    )
+}
@@ -1,7 +1,9 @@
 pub mod job;
+pub mod process_entry;
 pub mod process_frame;
 pub mod process_snapshot;
 
 pub use job::*;
+pub use process_entry::*;
 pub use process_frame::*;
 pub use process_snapshot::*;
@@ -0,0 +1,140 @@
+use anchor_lang::{prelude::*, InstructionData, solana_program::instruction::Instruction};
+use antegen_utils::thread::ThreadResponse;
+
+use super::build_snapshot_instruction;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DeleteSnapshotProcessEntry<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = !registry.locked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_SNAPSHOT,
+            snapshot.id.to_be_bytes().as_ref(),
+        ],
+        bump,
+        constraint = snapshot.id.lt(&registry.current_epoch)
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_SNAPSHOT_ENTRY,
+            snapshot_entry.snapshot_frame.as_ref(),
+            snapshot_entry.id.to_be_bytes().as_ref(),
+        ],
+        bump,
+        has_one = snapshot_frame,
+    )]
+    pub snapshot_entry: Account<'info, SnapshotEntry>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_SNAPSHOT_FRAME,
+            snapshot_frame.snapshot.as_ref(),
+            snapshot_frame.id.to_be_bytes().as_ref(),
+        ],
+        bump,
+        has_one = snapshot,
+    )]
+    pub snapshot_frame: Account<'info, SnapshotFrame>,
+
+    #[account(
+        mut, 
+        address = config.epoch_thread
+    )]
+    pub thread: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DeleteSnapshotProcessEntry>) -> Result<ThreadResponse> {
+    // Get accounts
+    let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let registry = &ctx.accounts.registry;
+    let snapshot = &mut ctx.accounts.snapshot;
+    let snapshot_entry = &mut ctx.accounts.snapshot_entry;
+    let snapshot_frame = &mut ctx.accounts.snapshot_frame;
+    let thread = &mut ctx.accounts.thread;
+
+    // Close the entry account.
+    snapshot_entry.close(thread.to_account_info())?;
+
+    // If this is the last entry in the frame, then close the frame account.
+    let is_last_entry = snapshot_entry.id.checked_add(1).unwrap().eq(&snapshot_frame.total_entries);
+    let is_last_frame = snapshot_frame.id.checked_add(1).unwrap().eq(&snapshot.total_frames);
+    if is_last_entry {
+        snapshot_frame.close(thread.to_account_info())?;
+
+        // If this is also the last frame in the snapshot, then close the snapshot account.
+        if is_last_frame {
+            snapshot.close(thread.to_account_info())?;
+            epoch_job.snapshot_deleted()?;
+        } else {
+            epoch_job.progress(snapshot_frame.id.checked_add(1).unwrap(), None)?;
+        }
+    } else {
+        epoch_job.progress(snapshot_frame.id, Some(snapshot_entry.id.checked_add(1).unwrap()))?;
+    }
+
+    // Build the next instruction.
+    let dynamic_instruction = if !is_last_entry {
+        // Move on to the next entry.
+        Some(
+            Instruction {
+                program_id: crate::ID,
+                accounts: crate::accounts::DeleteSnapshotProcessEntry {
+                    config: config.key(),
+                    epoch_job: epoch_job.key(),
+                    registry: registry.key(),
+                    snapshot: snapshot.key(),
+                    snapshot_entry: SnapshotEntry::pubkey(snapshot_frame.key(), snapshot_entry.id.checked_add(1).unwrap()),
+                    snapshot_frame: snapshot_frame.key(),
+                    thread: thread.key(),
+                }.to_account_metas(Some(true)),
+                data: crate::instruction::DeleteSnapshotProcessEntry {}.data()
+            }.into()
+        )
+    } else if !is_last_frame {
+        // Move on to the next frame.
+        Some(
+            Instruction {
+                program_id: crate::ID,
+                accounts: crate::accounts::DeleteSnapshotProcessFrame {
+                    config: config.key(),
+                    epoch_job: epoch_job.key(),
+                    registry: registry.key(),
+                    snapshot: snapshot.key(),
+                    snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id.checked_add(1).unwrap()),
+                    thread: thread.key(),
+                }.to_account_metas(Some(true)),
+                data: crate::instruction::DeleteSnapshotProcessFrame {}.data()
+            }.into()
+        )
+    } else {
+        // Move on to the next snapshot out of the retention window, if any.
+        let next_snapshot_instruction = build_snapshot_instruction(config, epoch_job, registry, thread);
+        if next_snapshot_instruction.is_none() {
+            epoch_job.complete()?;
+        }
+        next_snapshot_instruction
+    };
+
+    Ok(ThreadResponse { 
+        dynamic_instruction,
+        ..ThreadResponse::default() 
+    })
+}
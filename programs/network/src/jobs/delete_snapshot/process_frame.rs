@@ -1,6 +1,7 @@
 use anchor_lang::{prelude::*, InstructionData, solana_program::instruction::Instruction};
 use antegen_utils::thread::ThreadResponse;
 
+use super::build_snapshot_instruction;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -8,6 +9,9 @@ pub struct DeleteSnapshotProcessFrame<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(
         address = Registry::pubkey(),
         constraint = !registry.locked
@@ -47,17 +51,43 @@ pub struct DeleteSnapshotProcessFrame<'info> {
 pub fn handler(ctx: Context<DeleteSnapshotProcessFrame>) -> Result<ThreadResponse> {
     // Get accounts
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &ctx.accounts.registry;
     let snapshot = &mut ctx.accounts.snapshot;
     let snapshot_frame = &mut ctx.accounts.snapshot_frame;
     let thread = &mut ctx.accounts.thread;
 
+    // If this frame has entries, delete them first. The last entry closes the frame.
+    if snapshot_frame.total_entries.gt(&0) {
+        return Ok(ThreadResponse {
+            dynamic_instruction: Some(
+                Instruction {
+                    program_id: crate::ID,
+                    accounts: crate::accounts::DeleteSnapshotProcessEntry {
+                        config: config.key(),
+                        epoch_job: epoch_job.key(),
+                        registry: registry.key(),
+                        snapshot: snapshot.key(),
+                        snapshot_entry: SnapshotEntry::pubkey(snapshot_frame.key(), 0),
+                        snapshot_frame: snapshot_frame.key(),
+                        thread: thread.key(),
+                    }.to_account_metas(Some(true)),
+                    data: crate::instruction::DeleteSnapshotProcessEntry {}.data()
+                }.into()
+            ),
+            ..ThreadResponse::default()
+        });
+    }
+
     // Close the frame account.
     snapshot_frame.close(thread.to_account_info())?;
 
     // If this is also the last frame in the snapshot, then close the snapshot account.
     if snapshot_frame.id.checked_add(1).unwrap().eq(&snapshot.total_frames) {
         snapshot.close(thread.to_account_info())?;
+        epoch_job.snapshot_deleted()?;
+    } else {
+        epoch_job.progress(snapshot_frame.id.checked_add(1).unwrap(), None)?;
     }
 
     // Build the next instruction.
@@ -74,6 +104,7 @@ pub fn handler(ctx: Context<DeleteSnapshotProcessFrame>) -> Result<ThreadRespons
                 program_id: crate::ID,
                 accounts: crate::accounts::DeleteSnapshotProcessFrame {
                     config: config.key(),
+                    epoch_job: epoch_job.key(),
                     registry: registry.key(),
                     snapshot: snapshot.key(),
                     snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id.checked_add(1).unwrap()),
@@ -83,7 +114,12 @@ pub fn handler(ctx: Context<DeleteSnapshotProcessFrame>) -> Result<ThreadRespons
             }.into()
         )
     } else {
-        None
+        // Move on to the next snapshot out of the retention window, if any.
+        let next_snapshot_instruction = build_snapshot_instruction(config, epoch_job, registry, thread);
+        if next_snapshot_instruction.is_none() {
+            epoch_job.complete()?;
+        }
+        next_snapshot_instruction
     };
 
     Ok(ThreadResponse { 
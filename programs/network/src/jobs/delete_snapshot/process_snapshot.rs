@@ -1,6 +1,7 @@
 use antegen_utils::thread::ThreadResponse;
 use anchor_lang::{prelude::*, InstructionData, solana_program::instruction::Instruction};
 
+use super::build_snapshot_instruction;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -8,6 +9,9 @@ pub struct DeleteSnapshotProcessSnapshot<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(
         address = Registry::pubkey(),
         constraint = !registry.locked
@@ -21,6 +25,7 @@ pub struct DeleteSnapshotProcessSnapshot<'info> {
             snapshot.id.to_be_bytes().as_ref(),
         ],
         bump,
+        constraint = snapshot.id.eq(&epoch_job.next_snapshot),
         constraint = snapshot.id.lt(&registry.current_epoch)
     )]
     pub snapshot: Account<'info, Snapshot>,
@@ -35,6 +40,7 @@ pub struct DeleteSnapshotProcessSnapshot<'info> {
 pub fn handler(ctx: Context<DeleteSnapshotProcessSnapshot>) -> Result<ThreadResponse> {
     // Get accounts
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &ctx.accounts.registry;
     let snapshot = &mut ctx.accounts.snapshot;
     let thread = &mut ctx.accounts.thread;
@@ -48,27 +54,54 @@ pub fn handler(ctx: Context<DeleteSnapshotProcessSnapshot>) -> Result<ThreadResp
             .lamports()
             .checked_add(snapshot_lamports)
             .unwrap();
+        epoch_job.snapshot_deleted()?;
+
+        // Move on to the next snapshot out of the retention window, if any.
+        let dynamic_instruction = build_snapshot_instruction(config, epoch_job, registry, thread);
+        if dynamic_instruction.is_none() {
+            epoch_job.complete()?;
+        }
+        return Ok(ThreadResponse {
+            dynamic_instruction,
+            ..ThreadResponse::default()
+        });
     }
 
     // Build next instruction the thread.
-    let dynamic_instruction = if snapshot.total_frames.gt(&0) {
-        // There are frames in this snapshot. Delete them.
+    let dynamic_instruction = if let Some(next_entry) = epoch_job.next_entry {
+        // Resume deleting the entries of a partially deleted frame.
+        let snapshot_frame_pubkey = SnapshotFrame::pubkey(snapshot.key(), epoch_job.next_index);
+        Some(
+            Instruction {
+                program_id: crate::ID,
+                accounts: crate::accounts::DeleteSnapshotProcessEntry {
+                    config: config.key(),
+                    epoch_job: epoch_job.key(),
+                    registry: registry.key(),
+                    snapshot: snapshot.key(),
+                    snapshot_entry: SnapshotEntry::pubkey(snapshot_frame_pubkey, next_entry),
+                    snapshot_frame: snapshot_frame_pubkey,
+                    thread: thread.key(),
+                }.to_account_metas(Some(true)),
+                data: crate::instruction::DeleteSnapshotProcessEntry {}.data()
+            }.into()
+        )
+    } else {
+        // Delete the snapshot's frames, starting from the next undeleted frame.
         Some(
             Instruction {
                 program_id: crate::ID,
                 accounts: crate::accounts::DeleteSnapshotProcessFrame {
                     config: config.key(),
+                    epoch_job: epoch_job.key(),
                     registry: registry.key(),
                     snapshot: snapshot.key(),
-                    snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), 0),
+                    snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), epoch_job.next_index),
                     thread: thread.key(),
                 }.to_account_metas(Some(true)),
                 data: crate::instruction::DeleteSnapshotProcessFrame{}.data()
             }.into()
         )
-    } else {
-        // This snaphot has no frames. We are done!
-        None
     };
 
     Ok(ThreadResponse { dynamic_instruction, close_to:None, trigger: None })
@@ -1,6 +1,12 @@
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program},
+    InstructionData,
+};
 use antegen_utils::thread::ThreadResponse;
+use std::mem::size_of;
 
+use super::build_lookahead_accounts;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -8,6 +14,18 @@ pub struct DistributeFeesJob<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(
+        init_if_needed,
+        seeds = [SEED_EPOCH_JOB],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<EpochJob>(),
+    )]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         mut,
         seeds = [SEED_REGISTRY],
@@ -15,6 +33,9 @@ pub struct DistributeFeesJob<'info> {
     )]
     pub registry: Account<'info, Registry>,
 
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
     #[account(address = config.epoch_thread)]
     pub thread: Signer<'info>,
 }
@@ -22,24 +43,40 @@ pub struct DistributeFeesJob<'info> {
 pub fn handler(ctx: Context<DistributeFeesJob>) -> Result<ThreadResponse> {
     // Get accounts.
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &mut ctx.accounts.registry;
     let thread = &ctx.accounts.thread;
 
+    // Snapshots of earlier epochs were deleted before epoch jobs were tracked.
+    if epoch_job.updated_at.eq(&0) {
+        epoch_job.init(registry.current_epoch)?;
+    }
+
+    // Start closing out the current epoch, or resume a run which failed midway.
+    if !epoch_job.enter(EpochPhase::DistributeFees, registry.current_epoch)? {
+        return Ok(ThreadResponse::default());
+    }
+
     // Lock the registry.
     registry.locked = true;
 
-    // Process the snapshot.
+    // Process the snapshot, starting from the next unprocessed frame.
+    let snapshot_pubkey = Snapshot::pubkey(registry.current_epoch);
+    let mut accounts = crate::accounts::DistributeFeesProcessSnapshot {
+        config: config.key(),
+        epoch_job: epoch_job.key(),
+        registry: registry.key(),
+        snapshot: snapshot_pubkey,
+        snapshot_frame: SnapshotFrame::pubkey(snapshot_pubkey, epoch_job.next_index),
+        thread: thread.key(),
+    }
+    .to_account_metas(Some(true));
+    accounts.extend(build_lookahead_accounts(config, snapshot_pubkey, epoch_job.next_index));
     Ok(ThreadResponse {
         dynamic_instruction: Some(
             Instruction {
                 program_id: crate::ID,
-                accounts: crate::accounts::DistributeFeesProcessSnapshot {
-                    config: config.key(),
-                    registry: registry.key(),
-                    snapshot: Snapshot::pubkey(registry.current_epoch),
-                    thread: thread.key(),
-                }
-                .to_account_metas(Some(true)),
+                accounts,
                 data: crate::instruction::DistributeFeesProcessSnapshot {}.data(),
             }
             .into(),
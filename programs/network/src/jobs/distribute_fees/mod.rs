@@ -1,7 +1,9 @@
 pub mod job;
-pub mod process_worker;
+pub mod process_entry;
 pub mod process_snapshot;
+pub mod process_worker;
 
 pub use job::*;
-pub use process_worker::*;
+pub use process_entry::*;
 pub use process_snapshot::*;
+pub use process_worker::*;
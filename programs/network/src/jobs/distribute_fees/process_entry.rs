@@ -0,0 +1,177 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use antegen_utils::thread::{SerializableInstruction, ThreadResponse};
+
+use super::{build_lookahead_accounts, build_next_frame_instruction};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DistributeFeesProcessEntry<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_WORKER_COMMISSION,
+            commission.worker.as_ref(),
+        ],
+        bump,
+        has_one = worker,
+    )]
+    pub commission: Account<'info, WorkerCommission>,
+
+    /// CHECK: The delegation is only deserialized when it is paid. A missing delegation was snapshotted without stake.
+    #[account(mut, address = snapshot_entry.delegation)]
+    pub delegation: UncheckedAccount<'info>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The frame processed after this one. It is only read, and may not exist.
+    #[account(address = SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id.checked_add(1).unwrap()))]
+    pub next_snapshot_frame: UncheckedAccount<'info>,
+
+    #[account(address = Registry::pubkey())]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        address = snapshot.pubkey(),
+        constraint = snapshot.id.eq(&registry.current_epoch)
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(
+        address = snapshot_entry.pubkey(),
+        has_one = snapshot_frame,
+    )]
+    pub snapshot_entry: Account<'info, SnapshotEntry>,
+
+    #[account(
+        address = snapshot_frame.pubkey(),
+        has_one = snapshot,
+        has_one = worker,
+    )]
+    pub snapshot_frame: Account<'info, SnapshotFrame>,
+
+    #[account(address = config.epoch_thread)]
+    pub thread: Signer<'info>,
+
+    #[account(address = worker.pubkey())]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<DistributeFeesProcessEntry>) -> Result<ThreadResponse> {
+    // Get accounts.
+    let config: &Account<Config> = &ctx.accounts.config;
+    let commission: &mut Account<WorkerCommission> = &mut ctx.accounts.commission;
+    let delegation: &UncheckedAccount = &ctx.accounts.delegation;
+    let epoch_job: &mut Account<EpochJob> = &mut ctx.accounts.epoch_job;
+    let next_snapshot_frame: &UncheckedAccount = &ctx.accounts.next_snapshot_frame;
+    let registry: &Account<Registry> = &ctx.accounts.registry;
+    let snapshot: &Account<Snapshot> = &ctx.accounts.snapshot;
+    let snapshot_entry: &Account<SnapshotEntry> = &ctx.accounts.snapshot_entry;
+    let snapshot_frame: &Account<SnapshotFrame> = &ctx.accounts.snapshot_frame;
+    let thread: &Signer = &ctx.accounts.thread;
+
+    // Calculate the delegation's pro-rata share of the distributable balance.
+    let distribution_balance: u64 = if snapshot_frame.delegated_stake.gt(&0) {
+        (commission.distributable_balance as u128)
+            .checked_mul(snapshot_entry.stake_amount as u128)
+            .unwrap()
+            .checked_div(snapshot_frame.delegated_stake as u128)
+            .unwrap() as u64
+    } else {
+        0
+    };
+
+    // Transfer the share to the delegation's yield balance.
+    if distribution_balance.gt(&0) {
+        **commission.to_account_info().try_borrow_mut_lamports()? = commission
+            .to_account_info()
+            .lamports()
+            .checked_sub(distribution_balance)
+            .unwrap();
+        let mut delegation_data: Delegation =
+            Delegation::peek(delegation).ok_or(ErrorCode::AccountNotInitialized)?;
+        **delegation.to_account_info().try_borrow_mut_lamports()? = delegation
+            .to_account_info()
+            .lamports()
+            .checked_add(distribution_balance)
+            .unwrap();
+        delegation_data.yield_balance = delegation_data.yield_balance.checked_add(distribution_balance).unwrap();
+        delegation_data.try_serialize(&mut &mut delegation.try_borrow_mut_data()?[..])?;
+    }
+
+    // Record the progress and build the next instruction for the thread.
+    let next_entry_id: u64 = snapshot_entry.id.checked_add(1).unwrap();
+    let dynamic_instruction = if next_entry_id.lt(&snapshot_frame.total_entries) {
+        // Move on to the worker's next delegator.
+        epoch_job.progress(snapshot_frame.id, Some(next_entry_id))?;
+        Some(build_entry_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            snapshot_frame,
+            next_entry_id,
+        ))
+    } else {
+        // All delegators have been paid. Any rounding dust stays with the commission account.
+        commission.distributable_balance = 0;
+
+        epoch_job.progress(snapshot_frame.id.checked_add(1).unwrap(), None)?;
+        build_next_frame_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            next_snapshot_frame,
+            ctx.remaining_accounts,
+        )
+    };
+
+    Ok(ThreadResponse {
+        dynamic_instruction,
+        ..ThreadResponse::default()
+    })
+}
+
+/// Builds the instruction paying the given delegator of a frame's worker.
+/// The last delegator's instruction also reads the frames needed to gather the next batch.
+pub fn build_entry_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    snapshot: &Account<Snapshot>,
+    thread: &Signer,
+    snapshot_frame: &SnapshotFrame,
+    entry_id: u64,
+) -> SerializableInstruction {
+    let snapshot_frame_pubkey = SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id);
+    let next_frame_id = snapshot_frame.id.checked_add(1).unwrap();
+    let mut accounts = crate::accounts::DistributeFeesProcessEntry {
+        config: config.key(),
+        commission: WorkerCommission::pubkey(snapshot_frame.worker),
+        delegation: Delegation::pubkey(snapshot_frame.worker, entry_id),
+        epoch_job: epoch_job.key(),
+        next_snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), next_frame_id),
+        registry: registry.key(),
+        snapshot: snapshot.key(),
+        snapshot_entry: SnapshotEntry::pubkey(snapshot_frame_pubkey, entry_id),
+        snapshot_frame: snapshot_frame_pubkey,
+        thread: thread.key(),
+        worker: snapshot_frame.worker,
+    }
+    .to_account_metas(Some(true));
+    if entry_id.checked_add(1).unwrap().eq(&snapshot_frame.total_entries) {
+        accounts.extend(build_lookahead_accounts(config, snapshot.key(), next_frame_id));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: crate::instruction::DistributeFeesProcessEntry {}.data(),
+    }
+    .into()
+}
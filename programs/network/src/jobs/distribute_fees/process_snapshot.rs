@@ -1,13 +1,17 @@
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use anchor_lang::prelude::*;
 use antegen_utils::thread::ThreadResponse;
 
-use crate::{state::*, ANTEGEN_SQUADS};
+use super::{build_entry_instruction, build_next_frame_instruction};
+use crate::state::*;
 
 #[derive(Accounts)]
 pub struct DistributeFeesProcessSnapshot<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(seeds = [SEED_REGISTRY], bump)]
     pub registry: Account<'info, Registry>,
 
@@ -17,39 +21,49 @@ pub struct DistributeFeesProcessSnapshot<'info> {
     )]
     pub snapshot: Account<'info, Snapshot>,
 
+    /// CHECK: The next unprocessed frame. It is only read, and may not exist.
+    /// The remaining accounts hold the frames following it, which are read to gather the next batch.
+    #[account(address = SnapshotFrame::pubkey(snapshot.key(), epoch_job.next_index))]
+    pub snapshot_frame: UncheckedAccount<'info>,
+
     #[account(address = config.epoch_thread)]
     pub thread: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<DistributeFeesProcessSnapshot>) -> Result<ThreadResponse> {
     let config: &Account<Config> = &ctx.accounts.config;
+    let epoch_job: &Account<EpochJob> = &ctx.accounts.epoch_job;
     let registry: &mut Account<Registry> = &mut ctx.accounts.registry;
     let snapshot: &Account<Snapshot> = &ctx.accounts.snapshot;
+    let snapshot_frame: &UncheckedAccount = &ctx.accounts.snapshot_frame;
     let thread: &Signer = &ctx.accounts.thread;
 
+    // If the frame's worker was already paid, resume with its delegators.
+    if let (Some(next_entry), Some(frame)) = (epoch_job.next_entry, SnapshotFrame::peek(snapshot_frame)) {
+        return Ok(ThreadResponse {
+            dynamic_instruction: Some(build_entry_instruction(
+                config,
+                epoch_job,
+                registry,
+                snapshot,
+                thread,
+                &frame,
+                next_entry,
+            )),
+            ..ThreadResponse::default()
+        });
+    }
+
     Ok(ThreadResponse {
-        dynamic_instruction: if snapshot.total_frames.gt(&0) {
-            Some(
-                Instruction {
-                    program_id: crate::ID,
-                    accounts: crate::accounts::DistributeFeesProcessWorker {
-                        config: config.key(),
-                        commission: WorkerCommission::pubkey(Worker::pubkey(0)),
-                        registry: registry.key(),
-                        network_fee: ANTEGEN_SQUADS,
-                        snapshot: snapshot.key(),
-                        snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), 0),
-                        thread: thread.key(),
-                        worker: Worker::pubkey(0),
-                    }
-                    .to_account_metas(Some(true)),
-                    data: crate::instruction::DistributeFeesProcessWorker {}.data(),
-                }
-                .into(),
-            )
-        } else {
-            None
-        },
+        dynamic_instruction: build_next_frame_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            snapshot_frame,
+            ctx.remaining_accounts,
+        ),
         ..ThreadResponse::default()
     })
 }
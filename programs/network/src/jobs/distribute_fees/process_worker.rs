@@ -1,9 +1,20 @@
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
-use antegen_utils::thread::ThreadResponse;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        system_program,
+    },
+    InstructionData,
+};
+use antegen_utils::thread::{SerializableInstruction, ThreadResponse, PAYER_PUBKEY};
+use std::mem::size_of;
 
-use crate::{state::*, ANTEGEN_SQUADS};
+use super::build_entry_instruction;
+use crate::{errors::*, instructions::worker_claim::split_fees, state::*};
 
-pub const TOTAL_BASIS_POINTS: u64 = 10_000;
+/// The number of remaining accounts passed for each worker in a batch after the first:
+/// its snapshot frame, commission account and worker account.
+const BATCH_WORKER_ACCOUNTS: usize = 3;
 
 #[derive(Accounts)]
 pub struct DistributeFeesProcessWorker<'info> {
@@ -21,15 +32,36 @@ pub struct DistributeFeesProcessWorker<'info> {
     )]
     pub commission: Account<'info, WorkerCommission>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_EPOCH_SUMMARY,
+            snapshot.id.to_be_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<EpochSummary>(),
+    )]
+    pub epoch_summary: Option<Account<'info, EpochSummary>>,
+
     #[account(address = Registry::pubkey())]
     pub registry: Account<'info, Registry>,
 
     #[account(
         mut,
-        address = ANTEGEN_SQUADS
+        address = config.network_fee_recipient
     )]
     pub network_fee: SystemAccount<'info>,
 
+    /// CHECK: The frame processed after this batch. It is only read, and may not exist.
+    pub next_snapshot_frame: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         address = snapshot.pubkey(),
         constraint = snapshot.id.eq(&registry.current_epoch)
@@ -43,6 +75,9 @@ pub struct DistributeFeesProcessWorker<'info> {
     )]
     pub snapshot_frame: Account<'info, SnapshotFrame>,
 
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
     #[account(address = config.epoch_thread)]
     pub thread: Signer<'info>,
 
@@ -50,31 +85,151 @@ pub struct DistributeFeesProcessWorker<'info> {
     pub worker: Account<'info, Worker>,
 }
 
-pub fn handler(ctx: Context<DistributeFeesProcessWorker>) -> Result<ThreadResponse> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeFeesProcessWorker<'info>>,
+    batch_size: u64,
+) -> Result<ThreadResponse> {
     // Get accounts.
     let config: &Account<Config> = &ctx.accounts.config;
     let commission: &mut Account<WorkerCommission> = &mut ctx.accounts.commission;
+    let epoch_job: &mut Account<EpochJob> = &mut ctx.accounts.epoch_job;
+    let epoch_summary: &mut Option<Account<EpochSummary>> = &mut ctx.accounts.epoch_summary;
     let registry: &Account<Registry> = &ctx.accounts.registry;
     let network_fee: &mut SystemAccount = &mut ctx.accounts.network_fee;
+    let next_snapshot_frame: &UncheckedAccount = &ctx.accounts.next_snapshot_frame;
     let snapshot: &Account<Snapshot> = &ctx.accounts.snapshot;
     let snapshot_frame: &Account<SnapshotFrame> = &ctx.accounts.snapshot_frame;
     let thread: &Signer = &ctx.accounts.thread;
     let worker: &mut Account<Worker> = &mut ctx.accounts.worker;
 
-    // Calculate the fee account's usuable balance.
+    // The remaining accounts hold the frame, commission and worker of each worker in the batch after the first,
+    // followed by the frames after the next one, which are read to build the next batch.
+    let batch_len: usize = (batch_size.saturating_sub(1) as usize)
+        .checked_mul(BATCH_WORKER_ACCOUNTS)
+        .unwrap();
+    require!(
+        batch_size.gt(&0) && ctx.remaining_accounts.len().ge(&batch_len),
+        AntegenNetworkError::InvalidBatch
+    );
+    let (batch_accounts, lookahead) = ctx.remaining_accounts.split_at(batch_len);
+
+    // Pay the batch's first worker.
+    pay_worker(
+        config,
+        commission,
+        epoch_summary,
+        &network_fee.to_account_info(),
+        snapshot,
+        snapshot_frame,
+        worker,
+    )?;
+
+    // Pay the rest of the batch, in frame order. Only the last worker may have delegators to pay.
+    let mut last_frame: SnapshotFrame = (**snapshot_frame).clone();
+    for accounts in batch_accounts.chunks(BATCH_WORKER_ACCOUNTS) {
+        let batch_frame: Account<SnapshotFrame> = Account::try_from(&accounts[0])?;
+        let mut batch_commission: Account<WorkerCommission> = Account::try_from(&accounts[1])?;
+        let batch_worker: Account<Worker> = Account::try_from(&accounts[2])?;
+        require!(
+            last_frame.total_entries.eq(&0)
+                && batch_frame
+                    .key()
+                    .eq(&SnapshotFrame::pubkey(snapshot.key(), last_frame.id.checked_add(1).unwrap()))
+                && batch_commission.key().eq(&WorkerCommission::pubkey(batch_frame.worker))
+                && batch_worker.key().eq(&batch_frame.worker),
+            AntegenNetworkError::InvalidBatch
+        );
+        pay_worker(
+            config,
+            &mut batch_commission,
+            epoch_summary,
+            &network_fee.to_account_info(),
+            snapshot,
+            &batch_frame,
+            &batch_worker,
+        )?;
+        batch_commission.exit(&crate::ID)?;
+        last_frame = batch_frame.into_inner();
+    }
+    require!(
+        next_snapshot_frame
+            .key()
+            .eq(&SnapshotFrame::pubkey(snapshot.key(), last_frame.id.checked_add(1).unwrap())),
+        AntegenNetworkError::InvalidBatch
+    );
+
+    // Record the progress and build the next instruction for the thread.
+    let dynamic_instruction = if last_frame.total_entries.gt(&0) {
+        // Pay the last worker's delegators.
+        epoch_job.progress(last_frame.id, Some(0))?;
+        Some(build_entry_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            &last_frame,
+            0,
+        ))
+    } else {
+        epoch_job.progress(last_frame.id.checked_add(1).unwrap(), None)?;
+        build_next_frame_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            next_snapshot_frame,
+            lookahead,
+        )
+    };
+
+    Ok(ThreadResponse {
+        dynamic_instruction,
+        ..ThreadResponse::default()
+    })
+}
+
+/// Pays a frame's worker its commission and the network its fee, setting aside the rest for the worker's delegators.
+fn pay_worker(
+    config: &Config,
+    commission: &mut Account<WorkerCommission>,
+    epoch_summary: &mut Option<Account<EpochSummary>>,
+    network_fee: &AccountInfo,
+    snapshot: &Snapshot,
+    snapshot_frame: &SnapshotFrame,
+    worker: &Account<Worker>,
+) -> Result<()> {
+    // Calculate the fee account's usuable balance, excluding the delegators' share already split off by claims.
     let commission_lamport_balance: u64 = commission.to_account_info().lamports();
     let commission_data_len: usize = 8 + commission.try_to_vec()?.len();
     let commission_rent_balance: u64 = Rent::get().unwrap().minimum_balance(commission_data_len);
-    let commission_usable_balance: u64 = commission_lamport_balance.checked_sub(commission_rent_balance).unwrap();
-
-    // Calculate the commission to be retained by the worker.
-    let commission_bps: u64 = worker.commission_rate.checked_mul(100).unwrap(); // Convert percentage to basis points
-    let commission_balance: u64 = commission_usable_balance
-        .checked_mul(commission_bps)
+    let commission_usable_balance: u64 = commission_lamport_balance
+        .checked_sub(commission_rent_balance)
         .unwrap()
-        .checked_div(TOTAL_BASIS_POINTS)
+        .checked_sub(commission.delegator_balance)
+        .unwrap();
+
+    // Split the fees into the network's fee, the worker's commission and the delegators' share.
+    let commission_bps: u64 = config.commission_bps(worker.commission_rate, snapshot_frame.stake);
+    let (network_fee_balance, commission_balance, delegator_balance) =
+        split_fees(commission_usable_balance, config.network_fee_bps, commission_bps);
+
+    // The remaining balance includes the delegators' share split off by claims since the last distribution.
+    let remaining_balance: u64 = delegator_balance
+        .checked_add(commission.delegator_balance)
         .unwrap();
-    let registry_fees: u64 = commission_usable_balance.checked_sub(commission_balance).unwrap();
+    commission.delegator_balance = 0;
+
+    // Set aside the remaining balance for the worker's delegators, if any staked in this snapshot.
+    // Otherwise, the remaining balance goes to the network.
+    let registry_fees: u64 = if snapshot_frame.delegated_stake.gt(&0) {
+        commission.distributable_balance = remaining_balance;
+        network_fee_balance
+    } else {
+        commission.distributable_balance = 0;
+        network_fee_balance.checked_add(remaining_balance).unwrap()
+    };
 
     // Transfer commission to the worker.
     if commission_balance.gt(&0) {
@@ -106,40 +261,101 @@ pub fn handler(ctx: Context<DistributeFeesProcessWorker>) -> Result<ThreadRespon
             .unwrap();
     }
 
-    // Build next instruction for the thread.
-    let dynamic_instruction = if snapshot_frame
-        .id
-        .checked_add(1)
-        .unwrap()
-        .lt(&snapshot.total_frames)
-    {
-        let next_worker_pubkey: Pubkey = Worker::pubkey(worker.id.checked_add(1).unwrap());
-        let next_snapshot_frame_pubkey: Pubkey =
-            SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id.checked_add(1).unwrap());
-        Some(
-            Instruction {
-                program_id: crate::ID,
-                accounts: crate::accounts::DistributeFeesProcessWorker {
-                    config: config.key(),
-                    commission: WorkerCommission::pubkey(next_worker_pubkey),
-                    registry: registry.key(),
-                    network_fee: network_fee.key(),
-                    snapshot: snapshot.key(),
-                    snapshot_frame: next_snapshot_frame_pubkey,
-                    thread: thread.key(),
-                    worker: next_worker_pubkey,
-                }
-                .to_account_metas(Some(true)),
-                data: crate::instruction::DistributeFeesProcessWorker {}.data(),
+    // Record the worker's payouts in the epoch's summary, if the network keeps one.
+    if let Some(epoch_summary) = epoch_summary {
+        epoch_summary.record_payout(
+            snapshot.id,
+            worker.key(),
+            commission_usable_balance,
+            commission_balance,
+            commission.distributable_balance,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Builds the instruction processing the snapshot's next batch of frames, if there are any.
+/// Frames record their worker, since worker ids are not contiguous once workers close.
+/// A batch ends early at a worker with delegators, who are paid before moving on.
+pub fn build_next_frame_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    snapshot: &Account<Snapshot>,
+    thread: &Signer,
+    next_snapshot_frame: &AccountInfo,
+    lookahead: &[AccountInfo],
+) -> Option<SerializableInstruction> {
+    // Gather the consecutive frames of the next batch.
+    let mut frames: Vec<SnapshotFrame> = vec![];
+    let next_frames = std::iter::once(SnapshotFrame::peek(next_snapshot_frame))
+        .chain(lookahead.iter().map(SnapshotFrame::peek));
+    for next_frame in next_frames {
+        if (frames.len() as u64).ge(&config.batch_size.max(1))
+            || frames.last().is_some_and(|frame| frame.total_entries.gt(&0))
+        {
+            break;
+        }
+        let Some(frame) = next_frame else {
+            break;
+        };
+        let is_consecutive = frames
+            .last()
+            .is_none_or(|last| frame.id.eq(&last.id.checked_add(1).unwrap()));
+        if frame.snapshot.ne(&snapshot.key()) || frame.id.ge(&snapshot.total_frames) || !is_consecutive {
+            break;
+        }
+        frames.push(frame);
+    }
+    let (first_frame, batch_frames) = frames.split_first()?;
+    let next_frame_id = frames.last()?.id.checked_add(1).unwrap();
+
+    let mut accounts = crate::accounts::DistributeFeesProcessWorker {
+        config: config.key(),
+        commission: WorkerCommission::pubkey(first_frame.worker),
+        epoch_job: epoch_job.key(),
+        epoch_summary: config.epoch_summaries.then(|| EpochSummary::pubkey(snapshot.id)),
+        registry: registry.key(),
+        network_fee: config.network_fee_recipient,
+        next_snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), next_frame_id),
+        payer: PAYER_PUBKEY,
+        snapshot: snapshot.key(),
+        snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), first_frame.id),
+        system_program: system_program::ID,
+        thread: thread.key(),
+        worker: first_frame.worker,
+    }
+    .to_account_metas(Some(true));
+    for frame in batch_frames {
+        accounts.push(AccountMeta::new_readonly(SnapshotFrame::pubkey(snapshot.key(), frame.id), false));
+        accounts.push(AccountMeta::new(WorkerCommission::pubkey(frame.worker), false));
+        accounts.push(AccountMeta::new(frame.worker, false));
+    }
+    accounts.extend(build_lookahead_accounts(config, snapshot.key(), next_frame_id));
+
+    Some(
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: crate::instruction::DistributeFeesProcessWorker {
+                batch_size: frames.len() as u64,
             }
-            .into(),
-        )
-    } else {
-        None
-    };
+            .data(),
+        }
+        .into(),
+    )
+}
 
-    Ok(ThreadResponse {
-        dynamic_instruction,
-        ..ThreadResponse::default()
-    })
+/// Builds the remaining accounts read to gather the batch starting at the given frame:
+/// the frames following it, up to the network's batch size.
+pub fn build_lookahead_accounts(config: &Config, snapshot: Pubkey, next_frame_id: u64) -> Vec<AccountMeta> {
+    (1..config.batch_size.max(1))
+        .map(|offset| {
+            AccountMeta::new_readonly(
+                SnapshotFrame::pubkey(snapshot, next_frame_id.checked_add(offset).unwrap()),
+                false,
+            )
+        })
+        .collect()
 }
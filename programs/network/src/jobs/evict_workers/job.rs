@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use antegen_utils::thread::ThreadResponse;
+
+use super::build_process_pool_instruction;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct EvictWorkersJob<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The zeroth pool. It is only read, and may not have been created.
+    #[account(address = Pool::pubkey(0))]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = registry.locked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(address = config.epoch_thread)]
+    pub thread: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EvictWorkersJob>) -> Result<ThreadResponse> {
+    // Get accounts
+    let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let pool = &ctx.accounts.pool;
+    let registry = &ctx.accounts.registry;
+    let thread = &ctx.accounts.thread;
+
+    // Skip the job if it has already run for this epoch.
+    if !epoch_job.enter(EpochPhase::EvictWorkers, registry.current_epoch)? {
+        return Ok(ThreadResponse::default());
+    }
+
+    // Exit early if eviction is disabled.
+    if config.heartbeat_timeout.eq(&0) {
+        return Ok(ThreadResponse::default());
+    }
+
+    // Visit the zeroth pool, if the network has any. Eviction is idempotent, so a resumed job starts over.
+    Ok(ThreadResponse {
+        dynamic_instruction: build_process_pool_instruction(config, epoch_job, registry, thread, pool),
+        ..ThreadResponse::default()
+    })
+}
@@ -0,0 +1,5 @@
+pub mod job;
+pub mod process_pool;
+
+pub use job::*;
+pub use process_pool::*;
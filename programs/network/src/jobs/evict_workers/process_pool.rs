@@ -0,0 +1,115 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::instruction::{AccountMeta, Instruction},
+        InstructionData,
+    },
+    antegen_utils::thread::{SerializableInstruction, ThreadResponse},
+};
+
+#[derive(Accounts)]
+pub struct EvictWorkersProcessPool<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The pool visited after this one. It is only read, and may not have been created.
+    #[account(address = Pool::pubkey(pool.id.checked_add(1).unwrap()))]
+    pub next_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = pool.pubkey(),
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = registry.locked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(address = config.epoch_thread)]
+    pub thread: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EvictWorkersProcessPool>) -> Result<ThreadResponse> {
+    // Get accounts
+    let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let next_pool = &ctx.accounts.next_pool;
+    let pool = &mut ctx.accounts.pool;
+    let registry = &ctx.accounts.registry;
+    let thread = &ctx.accounts.thread;
+
+    // Verify the remaining accounts are the stats accounts of the pool's workers, in seat order.
+    let stats_accounts = ctx.remaining_accounts;
+    require!(
+        stats_accounts.len().eq(&pool.workers.len())
+            && pool
+                .workers
+                .iter()
+                .zip(stats_accounts.iter())
+                .all(|(worker, stats)| stats.key().eq(&WorkerStats::pubkey(*worker))),
+        AntegenNetworkError::InvalidWorkerStats
+    );
+
+    // Evict workers which have not posted a heartbeat within the timeout, opening their seats for rotation.
+    // Workers which have never posted a heartbeat have no stats to show they are live.
+    let slot = Clock::get()?.slot;
+    let workers = pool
+        .workers
+        .iter()
+        .zip(stats_accounts.iter())
+        .filter(|(_worker, stats)| {
+            WorkerStats::peek(stats)
+                .is_some_and(|stats| !stats.is_stale(slot, config.heartbeat_timeout))
+        })
+        .map(|(worker, _stats)| *worker)
+        .collect::<Vec<Pubkey>>();
+    pool.workers = workers;
+
+    // Record the progress and visit the next pool.
+    epoch_job.progress(pool.id.checked_add(1).unwrap(), None)?;
+    Ok(ThreadResponse {
+        dynamic_instruction: build_process_pool_instruction(config, epoch_job, registry, thread, next_pool),
+        ..ThreadResponse::default()
+    })
+}
+
+/// Builds the instruction visiting the given pool, passing its workers' stats as remaining accounts.
+/// Returns none once every pool has been visited.
+pub fn build_process_pool_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    thread: &Signer,
+    pool: &AccountInfo,
+) -> Option<SerializableInstruction> {
+    let pool = Pool::peek(pool)?;
+    let mut accounts = crate::accounts::EvictWorkersProcessPool {
+        config: config.key(),
+        epoch_job: epoch_job.key(),
+        next_pool: Pool::pubkey(pool.id.checked_add(1).unwrap()),
+        pool: Pool::pubkey(pool.id),
+        registry: registry.key(),
+        thread: thread.key(),
+    }
+    .to_account_metas(Some(true));
+    accounts.extend(
+        pool.workers
+            .iter()
+            .map(|worker| AccountMeta::new_readonly(WorkerStats::pubkey(*worker), false)),
+    );
+    Some(
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: crate::instruction::EvictWorkersProcessPool {}.data(),
+        }
+        .into(),
+    )
+}
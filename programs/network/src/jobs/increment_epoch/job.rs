@@ -8,6 +8,9 @@ pub struct EpochCutover<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(
         mut,
         seeds = [SEED_REGISTRY],
@@ -20,7 +23,14 @@ pub struct EpochCutover<'info> {
 }
 
 pub fn handler(ctx: Context<EpochCutover>) -> Result<ThreadResponse> {
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &mut ctx.accounts.registry;
+
+    // Skip the job if it has already run for this epoch.
+    if !epoch_job.enter(EpochPhase::IncrementEpoch, registry.current_epoch)? {
+        return Ok(ThreadResponse::default());
+    }
+
     registry.current_epoch = registry.current_epoch.checked_add(1).unwrap();
     registry.locked = false;
 
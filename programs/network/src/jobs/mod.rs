@@ -1,9 +1,11 @@
 pub mod delete_snapshot;
 pub mod distribute_fees;
+pub mod evict_workers;
 pub mod increment_epoch;
 pub mod take_snapshot;
 
 pub use delete_snapshot::*;
 pub use distribute_fees::*;
+pub use evict_workers::*;
 pub use increment_epoch::*;
 pub use take_snapshot::*;
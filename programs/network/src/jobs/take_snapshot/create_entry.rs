@@ -0,0 +1,176 @@
+use {
+    super::{build_lookahead_accounts, build_next_worker_instruction},
+    crate::state::*,
+    anchor_lang::{
+        prelude::*,
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    },
+    antegen_utils::thread::{SerializableInstruction, ThreadResponse, PAYER_PUBKEY},
+    std::mem::size_of,
+};
+
+#[derive(Accounts)]
+pub struct TakeSnapshotCreateEntry<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: The delegation is only read, and a missing delegation is recorded without stake.
+    #[account(address = Delegation::pubkey(worker.key(), snapshot_frame.total_entries))]
+    pub delegation: UncheckedAccount<'info>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The worker visited after this one. It is only read, and may have been closed.
+    #[account(address = Worker::pubkey(worker.id.checked_add(1).unwrap()))]
+    pub next_worker: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = registry.locked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        address = snapshot.pubkey(),
+        constraint = registry.current_epoch.checked_add(1).unwrap().eq(&snapshot.id),
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_SNAPSHOT_ENTRY,
+            snapshot_frame.key().as_ref(),
+            snapshot_frame.total_entries.to_be_bytes().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<SnapshotEntry>(),
+    )]
+    pub snapshot_entry: Account<'info, SnapshotEntry>,
+
+    #[account(
+        mut,
+        address = snapshot_frame.pubkey(),
+        has_one = snapshot,
+        has_one = worker,
+        constraint = snapshot_frame.total_entries < worker.total_delegations,
+    )]
+    pub snapshot_frame: Account<'info, SnapshotFrame>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = config.epoch_thread)]
+    pub thread: Signer<'info>,
+
+    #[account(address = worker.pubkey())]
+    pub worker: Account<'info, Worker>,
+}
+
+pub fn handler(ctx: Context<TakeSnapshotCreateEntry>) -> Result<ThreadResponse> {
+    // Get accounts.
+    let config = &ctx.accounts.config;
+    let delegation = &ctx.accounts.delegation;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let next_worker = &ctx.accounts.next_worker;
+    let registry = &ctx.accounts.registry;
+    let snapshot = &ctx.accounts.snapshot;
+    let snapshot_entry = &mut ctx.accounts.snapshot_entry;
+    let snapshot_frame = &mut ctx.accounts.snapshot_frame;
+    let thread = &ctx.accounts.thread;
+    let worker = &ctx.accounts.worker;
+
+    // Initialize snapshot entry account. A missing delegation is skipped with no stake, so the snapshot does not stall.
+    let stake_amount = Delegation::peek(delegation).map_or(0, |delegation| delegation.stake_amount);
+    snapshot_entry.init(
+        delegation.key(),
+        snapshot_frame.total_entries,
+        snapshot_frame.key(),
+        stake_amount,
+    )?;
+
+    // Update the snapshot frame's delegated stake.
+    snapshot_frame.delegated_stake = snapshot_frame
+        .delegated_stake
+        .checked_add(stake_amount)
+        .unwrap();
+    snapshot_frame.total_entries = snapshot_frame.total_entries.checked_add(1).unwrap();
+
+    // Record the progress and build the next instruction for the thread.
+    let dynamic_instruction = if snapshot_frame.total_entries.lt(&worker.total_delegations) {
+        // Move on to the worker's next delegation.
+        epoch_job.progress(worker.id, Some(snapshot_frame.total_entries))?;
+        Some(build_entry_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            snapshot_frame.key(),
+            worker,
+            snapshot_frame.total_entries,
+        ))
+    } else {
+        epoch_job.progress(worker.id.checked_add(1).unwrap(), None)?;
+        build_next_worker_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            next_worker,
+            ctx.remaining_accounts,
+        )
+    };
+
+    Ok(ThreadResponse {
+        dynamic_instruction,
+        ..ThreadResponse::default()
+    })
+}
+
+/// Builds the instruction recording the given delegation of a frame's worker.
+/// The last delegation's instruction also reads the workers needed to gather the next batch.
+pub fn build_entry_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    snapshot: &Account<Snapshot>,
+    thread: &Signer,
+    snapshot_frame: Pubkey,
+    worker: &Worker,
+    entry_id: u64,
+) -> SerializableInstruction {
+    let next_worker_id = worker.id.checked_add(1).unwrap();
+    let worker_pubkey = Worker::pubkey(worker.id);
+    let mut accounts = crate::accounts::TakeSnapshotCreateEntry {
+        config: config.key(),
+        delegation: Delegation::pubkey(worker_pubkey, entry_id),
+        epoch_job: epoch_job.key(),
+        next_worker: Worker::pubkey(next_worker_id),
+        payer: PAYER_PUBKEY,
+        registry: registry.key(),
+        snapshot: snapshot.key(),
+        snapshot_entry: SnapshotEntry::pubkey(snapshot_frame, entry_id),
+        snapshot_frame,
+        system_program: system_program::ID,
+        thread: thread.key(),
+        worker: worker_pubkey,
+    }
+    .to_account_metas(Some(true));
+    if entry_id.checked_add(1).unwrap().eq(&worker.total_delegations) {
+        accounts.extend(build_lookahead_accounts(config, next_worker_id));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: crate::instruction::TakeSnapshotCreateEntry {}.data(),
+    }
+    .into()
+}
@@ -1,19 +1,34 @@
 use {
-    crate::state::*,
+    super::build_entry_instruction,
+    crate::{errors::*, state::*},
     anchor_lang::{
         prelude::*,
-        solana_program::{instruction::Instruction, system_program},
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            system_program,
+        },
+        system_program::{create_account, CreateAccount},
         InstructionData,
     },
-    antegen_utils::thread::{ThreadResponse, PAYER_PUBKEY},
+    antegen_utils::thread::{SerializableInstruction, ThreadResponse, PAYER_PUBKEY},
     std::mem::size_of,
 };
 
+/// The number of remaining accounts passed for each worker in a batch after the first:
+/// its worker, stake, stats and snapshot frame accounts.
+const BATCH_WORKER_ACCOUNTS: usize = 4;
+
 #[derive(Accounts)]
 pub struct TakeSnapshotCreateFrame<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The worker visited after this batch. It is only read, and may have been closed.
+    pub next_worker: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -23,6 +38,15 @@ pub struct TakeSnapshotCreateFrame<'info> {
     )]
     pub registry: Account<'info, Registry>,
 
+    #[account(
+        init_if_needed,
+        seeds = [SEED_REGISTRY_STATS],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<RegistryStats>(),
+    )]
+    pub registry_stats: Account<'info, RegistryStats>,
+
     #[account(
         mut,
         seeds = [
@@ -31,7 +55,7 @@ pub struct TakeSnapshotCreateFrame<'info> {
         ],
         bump,
         constraint = registry.current_epoch.checked_add(1).unwrap().eq(&snapshot.id),
-        constraint = snapshot.total_frames < registry.total_workers,
+        constraint = snapshot.next_worker_id < registry.total_workers,
     )]
     pub snapshot: Account<'info, Snapshot>,
 
@@ -48,6 +72,30 @@ pub struct TakeSnapshotCreateFrame<'info> {
     )]
     pub snapshot_frame: Account<'info, SnapshotFrame>,
 
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STAKE,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<WorkerStake>(),
+    )]
+    pub stake: Account<'info, WorkerStake>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            SEED_WORKER_STATS,
+            worker.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<WorkerStats>(),
+    )]
+    pub stats: Account<'info, WorkerStats>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
@@ -56,56 +104,124 @@ pub struct TakeSnapshotCreateFrame<'info> {
 
     #[account(
         address = worker.pubkey(),
-        constraint = worker.id.eq(&snapshot.total_frames),
+        constraint = worker.id.eq(&snapshot.next_worker_id),
+        constraint = worker.is_active(),
     )]
     pub worker: Account<'info, Worker>,
 }
 
-pub fn handler(ctx: Context<TakeSnapshotCreateFrame>) -> Result<ThreadResponse> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeSnapshotCreateFrame<'info>>,
+    batch_size: u64,
+) -> Result<ThreadResponse> {
     // Get accounts.
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let next_worker = &ctx.accounts.next_worker;
+    let payer = &ctx.accounts.payer;
     let registry = &ctx.accounts.registry;
+    let registry_stats = &mut ctx.accounts.registry_stats;
     let snapshot = &mut ctx.accounts.snapshot;
     let snapshot_frame = &mut ctx.accounts.snapshot_frame;
+    let stake = &mut ctx.accounts.stake;
+    let stats = &mut ctx.accounts.stats;
     let system_program = &ctx.accounts.system_program;
     let thread = &ctx.accounts.thread;
     let worker = &ctx.accounts.worker;
 
-    // Initialize snapshot frame account.
+    // Initialize the stake account of workers registered before staking existed.
+    if !stake.is_initialized() {
+        stake.init(worker.key())?;
+    }
+
+    // Initialize the stats account of workers registered before statistics existed.
+    if !stats.is_initialized() {
+        stats.init(worker.key())?;
+    }
+
+    // Initialize snapshot frame account, recording the work performed in the closing epoch,
+    // and add that work to the network's statistics.
+    let activity = stats.activity(registry.current_epoch);
+    registry_stats.record(registry.current_epoch, activity)?;
     snapshot_frame.init(
+        activity,
         snapshot.total_frames,
         snapshot.key(),
+        stake.amount,
         worker.key(),
     )?;
 
-    // Update snapshot total workers.
+    // Update snapshot total frames and advance to the next worker.
     snapshot.total_frames = snapshot.total_frames.checked_add(1).unwrap();
+    snapshot.next_worker_id = worker.id.checked_add(1).unwrap();
 
-    // Build the next instruction for the thread.
-    let dynamic_instruction = if snapshot.total_frames.lt(&registry.total_workers) {
-        let next_snapshot_frame_pubkey =
-            SnapshotFrame::pubkey(snapshot.key(), snapshot_frame.id.checked_add(1).unwrap());
-        let next_worker_pubkey = Worker::pubkey(worker.id.checked_add(1).unwrap());
-        Some(
-            Instruction {
-                program_id: crate::ID,
-                accounts: crate::accounts::TakeSnapshotCreateFrame {
-                    config: config.key(),
-                    payer: PAYER_PUBKEY,
-                    registry: registry.key(),
-                    snapshot: snapshot.key(),
-                    snapshot_frame: next_snapshot_frame_pubkey,
-                    system_program: system_program.key(),
-                    thread: thread.key(),
-                    worker: next_worker_pubkey
-                }
-                .to_account_metas(Some(true)),
-                data: crate::instruction::TakeSnapshotCreateFrame {}.data(),
-            }
-            .into(),
-        )
+    // The remaining accounts hold the worker, stake, stats and frame of each worker in the batch after the first,
+    // followed by the workers after the next one, which are read to build the next batch.
+    let batch_len: usize = (batch_size.saturating_sub(1) as usize)
+        .checked_mul(BATCH_WORKER_ACCOUNTS)
+        .unwrap();
+    require!(
+        batch_size.gt(&0) && ctx.remaining_accounts.len().ge(&batch_len),
+        AntegenNetworkError::InvalidBatch
+    );
+    let (batch_accounts, lookahead) = ctx.remaining_accounts.split_at(batch_len);
+
+    // Create frames for the rest of the batch, in worker order. Only the last worker may have delegations to record.
+    let mut last_worker: Worker = (**worker).clone();
+    for accounts in batch_accounts.chunks(BATCH_WORKER_ACCOUNTS) {
+        let batch_worker = Worker::peek(&accounts[0]).ok_or(AntegenNetworkError::InvalidBatch)?;
+        let batch_worker_pubkey = accounts[0].key();
+        require!(
+            last_worker.total_delegations.eq(&0)
+                && batch_worker_pubkey.eq(&Worker::pubkey(snapshot.next_worker_id))
+                && batch_worker.is_active()
+                && accounts[1].key().eq(&WorkerStake::pubkey(batch_worker_pubkey))
+                && accounts[2].key().eq(&WorkerStats::pubkey(batch_worker_pubkey)),
+            AntegenNetworkError::InvalidBatch
+        );
+
+        // Workers registered before staking or statistics existed have neither stake nor activity to record.
+        let activity = WorkerStats::peek(&accounts[2])
+            .map(|stats| stats.activity(registry.current_epoch))
+            .unwrap_or_default();
+        registry_stats.record(registry.current_epoch, activity)?;
+        let stake_amount = WorkerStake::peek(&accounts[1]).map_or(0, |stake| stake.amount);
+        let mut batch_frame = create_batch_frame(payer, system_program, snapshot, &accounts[3])?;
+        batch_frame.init(
+            activity,
+            snapshot.total_frames,
+            snapshot.key(),
+            stake_amount,
+            batch_worker_pubkey,
+        )?;
+        batch_frame.exit(&crate::ID)?;
+
+        snapshot.total_frames = snapshot.total_frames.checked_add(1).unwrap();
+        snapshot.next_worker_id = batch_worker.id.checked_add(1).unwrap();
+        last_worker = batch_worker;
+    }
+    require!(
+        next_worker.key().eq(&Worker::pubkey(snapshot.next_worker_id)),
+        AntegenNetworkError::InvalidBatch
+    );
+
+    // Record the progress and build the next instruction for the thread.
+    let dynamic_instruction = if last_worker.total_delegations.gt(&0) {
+        // The last worker has delegations. Create a snapshot entry for the zeroth delegation.
+        epoch_job.progress(last_worker.id, Some(0))?;
+        Some(build_entry_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            SnapshotFrame::pubkey(snapshot.key(), snapshot.total_frames.checked_sub(1).unwrap()),
+            &last_worker,
+            0,
+        ))
     } else {
-        None
+        epoch_job.progress(last_worker.id.checked_add(1).unwrap(), None)?;
+        build_next_worker_instruction(config, epoch_job, registry, snapshot, thread, next_worker, lookahead)
     };
 
     Ok(ThreadResponse {
@@ -113,3 +229,151 @@ pub fn handler(ctx: Context<TakeSnapshotCreateFrame>) -> Result<ThreadResponse>
         ..ThreadResponse::default()
     })
 }
+
+/// Creates the frame account of a worker in the batch after the first, at the snapshot's next frame id.
+fn create_batch_frame<'info>(
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    snapshot: &Account<'info, Snapshot>,
+    snapshot_frame: &'info AccountInfo<'info>,
+) -> Result<Account<'info, SnapshotFrame>> {
+    let snapshot_pubkey = snapshot.key();
+    let id_bytes = snapshot.total_frames.to_be_bytes();
+    let (snapshot_frame_pubkey, bump) = Pubkey::find_program_address(
+        &[SEED_SNAPSHOT_FRAME, snapshot_pubkey.as_ref(), id_bytes.as_ref()],
+        &crate::ID,
+    );
+    require!(
+        snapshot_frame.key().eq(&snapshot_frame_pubkey),
+        AntegenNetworkError::InvalidBatch
+    );
+
+    // As with init_if_needed, reuse the account if it was already created.
+    if snapshot_frame.owner.ne(&crate::ID) {
+        let space = 8 + size_of::<SnapshotFrame>();
+        create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: snapshot_frame.clone(),
+                },
+                &[&[SEED_SNAPSHOT_FRAME, snapshot_pubkey.as_ref(), id_bytes.as_ref(), &[bump]]],
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+    }
+    Account::try_from_unchecked(snapshot_frame)
+}
+
+/// Builds the instruction visiting the snapshot's next batch of workers. Active workers get a frame,
+/// while deactivated or closed workers are skipped one at a time.
+/// A batch ends early at a worker with delegations, which are recorded before moving on.
+pub fn build_next_worker_instruction(
+    config: &Account<Config>,
+    epoch_job: &Account<EpochJob>,
+    registry: &Account<Registry>,
+    snapshot: &Account<Snapshot>,
+    thread: &Signer,
+    next_worker: &AccountInfo,
+    lookahead: &[AccountInfo],
+) -> Option<SerializableInstruction> {
+    // Exit early if every worker has been visited.
+    if snapshot.next_worker_id.ge(&registry.total_workers) {
+        return None;
+    }
+
+    // Gather the consecutive active workers of the next batch.
+    let mut workers: Vec<Worker> = vec![];
+    let next_workers = std::iter::once(Worker::peek(next_worker)).chain(lookahead.iter().map(Worker::peek));
+    for next_worker in next_workers {
+        if (workers.len() as u64).ge(&config.batch_size.max(1))
+            || workers.last().is_some_and(|worker| worker.total_delegations.gt(&0))
+        {
+            break;
+        }
+        let Some(worker) = next_worker.filter(|worker| worker.is_active()) else {
+            break;
+        };
+        let worker_id = snapshot.next_worker_id.checked_add(workers.len() as u64).unwrap();
+        if worker.id.ne(&worker_id) || worker.id.ge(&registry.total_workers) {
+            break;
+        }
+        workers.push(worker);
+    }
+
+    // Skip the next worker if it has exited the network.
+    let Some((first_worker, batch_workers)) = workers.split_first() else {
+        let mut accounts = crate::accounts::TakeSnapshotSkipWorker {
+            config: config.key(),
+            epoch_job: epoch_job.key(),
+            next_worker: Worker::pubkey(snapshot.next_worker_id.checked_add(1).unwrap()),
+            registry: registry.key(),
+            snapshot: snapshot.key(),
+            thread: thread.key(),
+            worker: next_worker.key(),
+        }
+        .to_account_metas(Some(true));
+        accounts.extend(build_lookahead_accounts(
+            config,
+            snapshot.next_worker_id.checked_add(1).unwrap(),
+        ));
+        return Some(
+            Instruction {
+                program_id: crate::ID,
+                accounts,
+                data: crate::instruction::TakeSnapshotSkipWorker {}.data(),
+            }
+            .into(),
+        );
+    };
+
+    let first_worker_pubkey = Worker::pubkey(first_worker.id);
+    let next_worker_id = workers.last()?.id.checked_add(1).unwrap();
+    let mut accounts = crate::accounts::TakeSnapshotCreateFrame {
+        config: config.key(),
+        epoch_job: epoch_job.key(),
+        next_worker: Worker::pubkey(next_worker_id),
+        payer: PAYER_PUBKEY,
+        registry: registry.key(),
+        registry_stats: RegistryStats::pubkey(),
+        snapshot: snapshot.key(),
+        snapshot_frame: SnapshotFrame::pubkey(snapshot.key(), snapshot.total_frames),
+        stake: WorkerStake::pubkey(first_worker_pubkey),
+        stats: WorkerStats::pubkey(first_worker_pubkey),
+        system_program: system_program::ID,
+        thread: thread.key(),
+        worker: first_worker_pubkey,
+    }
+    .to_account_metas(Some(true));
+    for (frame_id, worker) in (snapshot.total_frames.checked_add(1).unwrap()..).zip(batch_workers) {
+        let worker_pubkey = Worker::pubkey(worker.id);
+        accounts.push(AccountMeta::new_readonly(worker_pubkey, false));
+        accounts.push(AccountMeta::new_readonly(WorkerStake::pubkey(worker_pubkey), false));
+        accounts.push(AccountMeta::new_readonly(WorkerStats::pubkey(worker_pubkey), false));
+        accounts.push(AccountMeta::new(SnapshotFrame::pubkey(snapshot.key(), frame_id), false));
+    }
+    accounts.extend(build_lookahead_accounts(config, next_worker_id));
+
+    Some(
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: crate::instruction::TakeSnapshotCreateFrame {
+                batch_size: workers.len() as u64,
+            }
+            .data(),
+        }
+        .into(),
+    )
+}
+
+/// Builds the remaining accounts read to gather the batch starting at the given worker:
+/// the workers following it, up to the network's batch size.
+pub fn build_lookahead_accounts(config: &Config, next_worker_id: u64) -> Vec<AccountMeta> {
+    (1..config.batch_size.max(1))
+        .map(|offset| AccountMeta::new_readonly(Worker::pubkey(next_worker_id.checked_add(offset).unwrap()), false))
+        .collect()
+}
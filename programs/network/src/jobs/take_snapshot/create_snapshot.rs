@@ -1,11 +1,8 @@
 use {
+    super::{build_entry_instruction, build_next_worker_instruction},
     crate::state::*,
-    anchor_lang::{
-        prelude::*,
-        solana_program::{instruction::Instruction, system_program},
-        InstructionData,
-    },
-    antegen_utils::thread::{ThreadResponse, PAYER_PUBKEY},
+    anchor_lang::{prelude::*, solana_program::system_program},
+    antegen_utils::thread::ThreadResponse,
     std::mem::size_of,
 };
 
@@ -14,6 +11,14 @@ pub struct TakeSnapshotCreateSnapshot<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The next unvisited worker. It is only read, and may have been closed.
+    /// The remaining accounts hold the workers following it, which are read to gather the next batch.
+    #[account(address = Worker::pubkey(epoch_job.next_index))]
+    pub next_worker: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -45,40 +50,46 @@ pub struct TakeSnapshotCreateSnapshot<'info> {
 pub fn handler(ctx: Context<TakeSnapshotCreateSnapshot>) -> Result<ThreadResponse> {
     // Get accounts
     let config = &ctx.accounts.config;
+    let epoch_job = &ctx.accounts.epoch_job;
+    let next_worker = &ctx.accounts.next_worker;
     let registry = &ctx.accounts.registry;
     let snapshot = &mut ctx.accounts.snapshot;
-    let system_program = &ctx.accounts.system_program;
     let thread = &ctx.accounts.thread;
 
-    // Start a new snapshot.
-    snapshot.init(registry.current_epoch.checked_add(1).unwrap())?;
+    // Start a new snapshot, unless resuming one which was partially taken.
+    let snapshot_id = registry.current_epoch.checked_add(1).unwrap();
+    if snapshot.id.ne(&snapshot_id) {
+        snapshot.init(snapshot_id)?;
+    }
+
+    // If the last frame's delegations were partially recorded, resume with them.
+    if let (Some(next_entry), Some(worker)) = (epoch_job.next_entry, Worker::peek(next_worker)) {
+        return Ok(ThreadResponse {
+            dynamic_instruction: Some(build_entry_instruction(
+                config,
+                epoch_job,
+                registry,
+                snapshot,
+                thread,
+                SnapshotFrame::pubkey(snapshot.key(), snapshot.total_frames.checked_sub(1).unwrap()),
+                &worker,
+                next_entry,
+            )),
+            ..ThreadResponse::default()
+        });
+    }
 
+    // Visit the next unvisited worker, if the registry has any.
     Ok(ThreadResponse {
-        dynamic_instruction: if registry.total_workers.gt(&0) {
-            // The registry has workers. Create a snapshot frame for the zeroth worker.
-            let snapshot_frame_pubkey = SnapshotFrame::pubkey(snapshot.key(), 0);
-            let worker_pubkey = Worker::pubkey(0);
-            Some(
-                Instruction {
-                    program_id: crate::ID,
-                    accounts: crate::accounts::TakeSnapshotCreateFrame {
-                        config: config.key(),
-                        payer: PAYER_PUBKEY,
-                        registry: registry.key(),
-                        snapshot: snapshot.key(),
-                        snapshot_frame: snapshot_frame_pubkey,
-                        system_program: system_program.key(),
-                        thread: thread.key(),
-                        worker: worker_pubkey,
-                    }
-                    .to_account_metas(Some(true)),
-                    data: crate::instruction::TakeSnapshotCreateFrame {}.data(),
-                }
-                .into(),
-            )
-        } else {
-            None
-        },
+        dynamic_instruction: build_next_worker_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            next_worker,
+            ctx.remaining_accounts,
+        ),
         ..ThreadResponse::default()
     })
 }
@@ -5,6 +5,7 @@ use anchor_lang::{
 };
 use antegen_utils::thread::{ThreadResponse, PAYER_PUBKEY};
 
+use super::build_lookahead_accounts;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -12,6 +13,9 @@ pub struct TakeSnapshotJob<'info> {
     #[account(address = Config::pubkey())]
     pub config: Account<'info, Config>,
 
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
     #[account(
         address = Registry::pubkey(),
         constraint = registry.locked
@@ -25,22 +29,33 @@ pub struct TakeSnapshotJob<'info> {
 pub fn handler(ctx: Context<TakeSnapshotJob>) -> Result<ThreadResponse> {
     // Get accounts
     let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
     let registry = &ctx.accounts.registry;
     let thread = &ctx.accounts.thread;
 
+    // Skip the job if it has already run for this epoch.
+    if !epoch_job.enter(EpochPhase::TakeSnapshot, registry.current_epoch)? {
+        return Ok(ThreadResponse::default());
+    }
+
+    // Create the snapshot, resuming from the next unvisited worker.
+    let mut accounts = crate::accounts::TakeSnapshotCreateSnapshot {
+        config: config.key(),
+        epoch_job: epoch_job.key(),
+        next_worker: Worker::pubkey(epoch_job.next_index),
+        payer: PAYER_PUBKEY,
+        registry: registry.key(),
+        snapshot: Snapshot::pubkey(registry.current_epoch.checked_add(1).unwrap()),
+        system_program: system_program::ID,
+        thread: thread.key(),
+    }
+    .to_account_metas(Some(true));
+    accounts.extend(build_lookahead_accounts(config, epoch_job.next_index));
     Ok(ThreadResponse {
         dynamic_instruction: Some(
             Instruction {
                 program_id: crate::ID,
-                accounts: crate::accounts::TakeSnapshotCreateSnapshot {
-                    config: config.key(),
-                    payer: PAYER_PUBKEY,
-                    registry: registry.key(),
-                    snapshot: Snapshot::pubkey(registry.current_epoch.checked_add(1).unwrap()),
-                    system_program: system_program::ID,
-                    thread: thread.key(),
-                }
-                .to_account_metas(Some(true)),
+                accounts,
                 data: crate::instruction::TakeSnapshotCreateSnapshot {}.data(),
             }
             .into(),
@@ -1,7 +1,11 @@
+pub mod create_entry;
 pub mod create_frame;
 pub mod create_snapshot;
 pub mod job;
+pub mod skip_worker;
 
+pub use create_entry::*;
 pub use create_frame::*;
 pub use create_snapshot::*;
 pub use job::*;
+pub use skip_worker::*;
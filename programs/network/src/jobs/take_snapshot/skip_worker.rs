@@ -0,0 +1,79 @@
+use {
+    super::build_next_worker_instruction,
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+    antegen_utils::thread::ThreadResponse,
+};
+
+#[derive(Accounts)]
+pub struct TakeSnapshotSkipWorker<'info> {
+    #[account(address = Config::pubkey())]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = EpochJob::pubkey())]
+    pub epoch_job: Account<'info, EpochJob>,
+
+    /// CHECK: The worker visited after this one. It is only read, and may have been closed.
+    /// The remaining accounts hold the workers following it, which are read to gather the next batch.
+    #[account(address = Worker::pubkey(snapshot.next_worker_id.checked_add(1).unwrap()))]
+    pub next_worker: UncheckedAccount<'info>,
+
+    #[account(
+        address = Registry::pubkey(),
+        constraint = registry.locked
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_SNAPSHOT,
+            snapshot.id.to_be_bytes().as_ref(),
+        ],
+        bump,
+        constraint = registry.current_epoch.checked_add(1).unwrap().eq(&snapshot.id),
+        constraint = snapshot.next_worker_id < registry.total_workers,
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(address = config.epoch_thread)]
+    pub thread: Signer<'info>,
+
+    /// CHECK: The deactivated or closed worker being skipped.
+    #[account(address = Worker::pubkey(snapshot.next_worker_id))]
+    pub worker: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<TakeSnapshotSkipWorker>) -> Result<ThreadResponse> {
+    // Get accounts.
+    let config = &ctx.accounts.config;
+    let epoch_job = &mut ctx.accounts.epoch_job;
+    let next_worker = &ctx.accounts.next_worker;
+    let registry = &ctx.accounts.registry;
+    let snapshot = &mut ctx.accounts.snapshot;
+    let thread = &ctx.accounts.thread;
+    let worker = &ctx.accounts.worker;
+
+    // Verify the worker has exited the network.
+    require!(
+        !Worker::peek(worker).is_some_and(|worker| worker.is_active()),
+        AntegenNetworkError::WorkerActive
+    );
+
+    // Advance to the next worker without creating a frame.
+    snapshot.next_worker_id = snapshot.next_worker_id.checked_add(1).unwrap();
+    epoch_job.progress(snapshot.next_worker_id, None)?;
+
+    Ok(ThreadResponse {
+        dynamic_instruction: build_next_worker_instruction(
+            config,
+            epoch_job,
+            registry,
+            snapshot,
+            thread,
+            next_worker,
+            ctx.remaining_accounts,
+        ),
+        ..ThreadResponse::default()
+    })
+}
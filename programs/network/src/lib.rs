@@ -16,17 +16,50 @@ use state::*;
 
 declare_id!("AgNet6qmh75bjFULcS9RQijUoWwkCtSiSwXM1K3Ujn6Z");
 pub const ANTEGEN_SQUADS: Pubkey = pubkey!("14b1BKm2md7GgP6ccZd2u4cAvBsqsmFjxokzQUXoqGzC");
+pub const THREAD_PROGRAM_ID: Pubkey = pubkey!("AgThdyi1P5RkVeZD2rQahTvs8HePJoGFFxKtvok5s2J1");
+/// The seed of the thread program's PDA which signs its reports of worker activity.
+/// The thread program never signs a thread's own instructions with it, so reports cannot be forged by thread authorities.
+pub const SEED_THREAD_STATS_AUTHORITY: &[u8] = b"stats_authority";
 pub const EPOCH_THREAD_ID: &str = "antegen.network.epoch";
 pub const HASHER_THREAD_ID: &str = "antegen.network.hasher";
 
+/// The address of the thread program's stats authority.
+pub fn thread_stats_authority() -> Pubkey {
+    Pubkey::find_program_address(&[SEED_THREAD_STATS_AUTHORITY], &THREAD_PROGRAM_ID).0
+}
+
 #[program]
 pub mod network_program {
     pub use super::*;
 
+    pub fn config_migrate(ctx: Context<ConfigMigrate>) -> Result<()> {
+        config_migrate::handler(ctx)
+    }
+
     pub fn config_update(ctx: Context<ConfigUpdate>, settings: ConfigSettings) -> Result<()> {
         config_update::handler(ctx, settings)
     }
 
+    pub fn delegation_claim(ctx: Context<DelegationClaim>) -> Result<()> {
+        delegation_claim::handler(ctx)
+    }
+
+    pub fn delegation_create(ctx: Context<DelegationCreate>) -> Result<()> {
+        delegation_create::handler(ctx)
+    }
+
+    pub fn delegation_deposit(ctx: Context<DelegationDeposit>, amount: u64) -> Result<()> {
+        delegation_deposit::handler(ctx, amount)
+    }
+
+    pub fn delegation_unbond(ctx: Context<DelegationUnbond>, amount: u64) -> Result<()> {
+        delegation_unbond::handler(ctx, amount)
+    }
+
+    pub fn delegation_withdraw(ctx: Context<DelegationWithdraw>) -> Result<()> {
+        delegation_withdraw::handler(ctx)
+    }
+
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         initialize::handler(ctx)
     }
@@ -35,6 +68,10 @@ pub mod network_program {
         pool_create::handler(ctx)
     }
 
+    pub fn pool_migrate(ctx: Context<PoolMigrate>, id: u64) -> Result<()> {
+        pool_migrate::handler(ctx, id)
+    }
+
     pub fn pool_rotate(ctx: Context<PoolRotate>) -> Result<()> {
         pool_rotate::handler(ctx)
     }
@@ -43,6 +80,20 @@ pub mod network_program {
         pool_update::handler(ctx, settings)
     }
 
+    pub fn proposal_cancel(ctx: Context<ProposalCancel>) -> Result<()> {
+        proposal_cancel::handler(ctx)
+    }
+
+    pub fn proposal_create(ctx: Context<ProposalCreate>, action: ProposalAction) -> Result<()> {
+        proposal_create::handler(ctx, action)
+    }
+
+    pub fn proposal_execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProposalExecute<'info>>,
+    ) -> Result<()> {
+        proposal_execute::handler(ctx)
+    }
+
     pub fn registry_nonce_hash(ctx: Context<RegistryNonceHash>) -> Result<ThreadResponse> {
         registry_nonce_hash::handler(ctx)
     }
@@ -55,10 +106,58 @@ pub mod network_program {
         registry_unlock::handler(ctx)
     }
 
+    pub fn snapshot_migrate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SnapshotMigrate<'info>>,
+        id: u64,
+    ) -> Result<()> {
+        snapshot_migrate::handler(ctx, id)
+    }
+
+    pub fn worker_claim(ctx: Context<WorkerClaim>) -> Result<()> {
+        worker_claim::handler(ctx)
+    }
+
+    pub fn worker_close(ctx: Context<WorkerClose>) -> Result<()> {
+        worker_close::handler(ctx)
+    }
+
     pub fn worker_create(ctx: Context<WorkerCreate>) -> Result<()> {
         worker_create::handler(ctx)
     }
 
+    pub fn worker_deactivate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WorkerDeactivate<'info>>,
+    ) -> Result<()> {
+        worker_deactivate::handler(ctx)
+    }
+
+    pub fn worker_heartbeat(ctx: Context<WorkerHeartbeat>) -> Result<()> {
+        worker_heartbeat::handler(ctx)
+    }
+
+    pub fn worker_migrate(ctx: Context<WorkerMigrate>, id: u64) -> Result<()> {
+        worker_migrate::handler(ctx, id)
+    }
+
+    pub fn worker_stake_deposit(ctx: Context<WorkerStakeDeposit>, amount: u64) -> Result<()> {
+        worker_stake_deposit::handler(ctx, amount)
+    }
+
+    pub fn worker_stake_unbond(ctx: Context<WorkerStakeUnbond>, amount: u64) -> Result<()> {
+        worker_stake_unbond::handler(ctx, amount)
+    }
+
+    pub fn worker_stake_withdraw(ctx: Context<WorkerStakeWithdraw>) -> Result<()> {
+        worker_stake_withdraw::handler(ctx)
+    }
+
+    pub fn worker_stats_record(
+        ctx: Context<WorkerStatsRecord>,
+        activity: WorkerActivity,
+    ) -> Result<()> {
+        worker_stats_record::handler(ctx, activity)
+    }
+
     pub fn worker_update(ctx: Context<WorkerUpdate>, settings: WorkerSettings) -> Result<()> {
         worker_update::handler(ctx, settings)
     }
@@ -68,10 +167,17 @@ pub mod network_program {
         jobs::distribute_fees::job::handler(ctx)
     }
 
-    pub fn distribute_fees_process_worker(
-        ctx: Context<DistributeFeesProcessWorker>,
+    pub fn distribute_fees_process_entry(
+        ctx: Context<DistributeFeesProcessEntry>,
+    ) -> Result<ThreadResponse> {
+        jobs::distribute_fees::process_entry::handler(ctx)
+    }
+
+    pub fn distribute_fees_process_worker<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeFeesProcessWorker<'info>>,
+        batch_size: u64,
     ) -> Result<ThreadResponse> {
-        jobs::distribute_fees::process_worker::handler(ctx)
+        jobs::distribute_fees::process_worker::handler(ctx, batch_size)
     }
 
     pub fn distribute_fees_process_snapshot(
@@ -85,10 +191,17 @@ pub mod network_program {
         jobs::take_snapshot::job::handler(ctx)
     }
 
-    pub fn take_snapshot_create_frame(
-        ctx: Context<TakeSnapshotCreateFrame>,
+    pub fn take_snapshot_create_entry(
+        ctx: Context<TakeSnapshotCreateEntry>,
     ) -> Result<ThreadResponse> {
-        jobs::take_snapshot::create_frame::handler(ctx)
+        jobs::take_snapshot::create_entry::handler(ctx)
+    }
+
+    pub fn take_snapshot_create_frame<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeSnapshotCreateFrame<'info>>,
+        batch_size: u64,
+    ) -> Result<ThreadResponse> {
+        jobs::take_snapshot::create_frame::handler(ctx, batch_size)
     }
 
     pub fn take_snapshot_create_snapshot(
@@ -97,6 +210,23 @@ pub mod network_program {
         jobs::take_snapshot::create_snapshot::handler(ctx)
     }
 
+    pub fn take_snapshot_skip_worker(
+        ctx: Context<TakeSnapshotSkipWorker>,
+    ) -> Result<ThreadResponse> {
+        jobs::take_snapshot::skip_worker::handler(ctx)
+    }
+
+    // EvictWorkers job
+    pub fn evict_workers_job(ctx: Context<EvictWorkersJob>) -> Result<ThreadResponse> {
+        jobs::evict_workers::job::handler(ctx)
+    }
+
+    pub fn evict_workers_process_pool(
+        ctx: Context<EvictWorkersProcessPool>,
+    ) -> Result<ThreadResponse> {
+        jobs::evict_workers::process_pool::handler(ctx)
+    }
+
     // IncrementEpoch job
     pub fn increment_epoch(ctx: Context<EpochCutover>) -> Result<ThreadResponse> {
         jobs::increment_epoch::job::handler(ctx)
@@ -113,6 +243,12 @@ pub mod network_program {
         jobs::delete_snapshot::process_snapshot::handler(ctx)
     }
 
+    pub fn delete_snapshot_process_entry(
+        ctx: Context<DeleteSnapshotProcessEntry>,
+    ) -> Result<ThreadResponse> {
+        jobs::delete_snapshot::process_entry::handler(ctx)
+    }
+
     pub fn delete_snapshot_process_frame(
         ctx: Context<DeleteSnapshotProcessFrame>,
     ) -> Result<ThreadResponse> {
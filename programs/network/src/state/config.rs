@@ -1,6 +1,30 @@
 use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use crate::{errors::*, state::MAX_COMMISSION_RATE, ANTEGEN_SQUADS};
+
 pub const SEED_CONFIG: &[u8] = b"config";
 
+/// The default number of slots a worker's stake takes to unbond (roughly two days).
+const DEFAULT_UNBONDING_PERIOD: u64 = 432_000;
+
+/// The default number of seconds between epochs.
+const DEFAULT_EPOCH_LENGTH: u64 = 60;
+
+/// The default number of seconds between registry nonce hashes.
+const DEFAULT_HASHER_INTERVAL: u64 = 15;
+
+/// The default number of lamports the network threads are funded with (one SOL).
+const DEFAULT_THREAD_FUNDING: u64 = 1_000_000_000;
+
+/// The most workers the epoch jobs may process per instruction, bounded by the size of a transaction.
+pub const MAX_BATCH_SIZE: u64 = 4;
+
+/// The number of basis points in 100%.
+pub const TOTAL_BASIS_POINTS: u64 = 10_000;
+
+/// The largest commission rate, as a percentage, the network may allow workers to keep.
+pub const COMMISSION_RATE_LIMIT: u64 = 100;
+
 /**
  * Config
  */
@@ -10,24 +34,143 @@ pub const SEED_CONFIG: &[u8] = b"config";
 pub struct Config {
     pub admin: Pubkey,
     pub epoch_thread: Pubkey,
-    pub hasher_thread: Pubkey
+    pub hasher_thread: Pubkey,
+    pub fee_mint: Option<Pubkey>,
+    pub fee_price_feed: Option<Pubkey>,
+    pub min_stake: u64,
+    pub unbonding_period: u64,
+    pub max_commission_rate: u64,
+    pub min_commission_rate: u64,
+    pub network_fee_bps: u64,
+    pub network_fee_recipient: Pubkey,
+    pub timelock: u64,
+    pub total_proposals: u64,
+    pub heartbeat_timeout: u64,
+    pub epoch_summaries: bool,
+    pub snapshot_retention: u64,
+    pub batch_size: u64,
+    pub epoch_length: u64,
+    pub hasher_interval: u64,
+    pub thread_funding: u64,
 }
 
 impl Config {
     pub fn pubkey() -> Pubkey {
         Pubkey::find_program_address(&[SEED_CONFIG], &crate::ID).0
     }
+
+    /// A config with the network's default settings.
+    pub fn new(admin: Pubkey, network_fee_recipient: Pubkey) -> Self {
+        Config {
+            admin,
+            epoch_thread: Pubkey::default(),
+            hasher_thread: Pubkey::default(),
+            fee_mint: None,
+            fee_price_feed: None,
+            min_stake: 0,
+            unbonding_period: DEFAULT_UNBONDING_PERIOD,
+            max_commission_rate: MAX_COMMISSION_RATE,
+            min_commission_rate: 0,
+            network_fee_bps: 0,
+            network_fee_recipient,
+            timelock: 0,
+            total_proposals: 0,
+            heartbeat_timeout: 0,
+            epoch_summaries: false,
+            snapshot_retention: 0,
+            batch_size: 1,
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            hasher_interval: DEFAULT_HASHER_INTERVAL,
+            thread_funding: DEFAULT_THREAD_FUNDING,
+        }
+    }
+
+    /// The cron schedule running a network thread every given number of seconds, if the interval
+    /// evenly divides a minute, an hour or a day.
+    pub fn cron_schedule(interval: u64) -> Option<String> {
+        match interval {
+            1..=59 if (60 % interval).eq(&0) => Some(format!("*/{} * * * * * *", interval)),
+            60..=3599 if (interval % 60).eq(&0) && (3600 % interval).eq(&0) => {
+                Some(format!("0 */{} * * * * *", interval / 60))
+            }
+            3600..=86399 if (interval % 3600).eq(&0) && (86400 % interval).eq(&0) => {
+                Some(format!("0 0 */{} * * * *", interval / 3600))
+            }
+            86400 => Some("0 0 0 * * * *".into()),
+            _ => None,
+        }
+    }
+
+    /// Clamps a worker's commission rate to the range currently allowed by the network.
+    pub fn commission_rate(&self, commission_rate: u64) -> u64 {
+        commission_rate.clamp(self.min_commission_rate, self.max_commission_rate)
+    }
+
+    /// The share of a worker's fees it retains as commission, in basis points.
+    /// Workers whose stake was below the network minimum at snapshot time forfeit their commission.
+    pub fn commission_bps(&self, commission_rate: u64, snapshot_stake: u64) -> u64 {
+        if snapshot_stake.lt(&self.min_stake) {
+            return 0;
+        }
+        self.commission_rate(commission_rate).checked_mul(100).unwrap() // Convert percentage to basis points
+    }
+}
+
+/**
+ * LegacyConfig
+ */
+
+/// The layout of the config created before the network's settings were appended, read by `config_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacyConfig {
+    pub admin: Pubkey,
+    pub epoch_thread: Pubkey,
+    pub hasher_thread: Pubkey,
+}
+
+impl From<LegacyConfig> for Config {
+    fn from(legacy: LegacyConfig) -> Self {
+        Config {
+            epoch_thread: legacy.epoch_thread,
+            hasher_thread: legacy.hasher_thread,
+            ..Config::new(legacy.admin, ANTEGEN_SQUADS)
+        }
+    }
 }
 
 /**
  * ConfigSettings
  */
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct ConfigSettings {
     pub admin: Pubkey,
+    /// The maximum number of workers the epoch jobs process per instruction.
+    pub batch_size: u64,
+    /// The number of seconds between epochs. Must evenly divide a minute, an hour or a day.
+    pub epoch_length: u64,
+    /// Whether to record a summary of each epoch's fee distribution which outlives its snapshot.
+    pub epoch_summaries: bool,
     pub epoch_thread: Pubkey,
-    pub hasher_thread: Pubkey
+    pub fee_mint: Option<Pubkey>,
+    pub fee_price_feed: Option<Pubkey>,
+    /// The number of seconds between registry nonce hashes. Must evenly divide a minute, an hour or a day.
+    pub hasher_interval: u64,
+    pub hasher_thread: Pubkey,
+    /// The number of slots without a heartbeat after which a worker is evicted from its pools. Zero disables eviction.
+    pub heartbeat_timeout: u64,
+    pub max_commission_rate: u64,
+    pub min_commission_rate: u64,
+    pub min_stake: u64,
+    pub network_fee_bps: u64,
+    pub network_fee_recipient: Pubkey,
+    /// The number of past epochs whose snapshots are kept on-chain. Zero deletes each snapshot once its epoch closes.
+    pub snapshot_retention: u64,
+    /// The number of lamports the network threads are funded with.
+    pub thread_funding: u64,
+    /// The number of slots proposed admin changes must wait before they can be executed.
+    pub timelock: u64,
+    pub unbonding_period: u64,
 }
 
 /**
@@ -35,21 +178,97 @@ pub struct ConfigSettings {
  */
 
 pub trait ConfigAccount {
-    fn init(&mut self, admin: Pubkey) -> Result<()>;
+    fn init(&mut self, admin: Pubkey, network_fee_recipient: Pubkey) -> Result<()>;
 
     fn update(&mut self, settings: ConfigSettings) -> Result<()>;
 }
 
 impl ConfigAccount for Account<'_, Config> {
-    fn init(&mut self, admin: Pubkey) -> Result<()> {
-        self.admin = admin;
+    fn init(&mut self, admin: Pubkey, network_fee_recipient: Pubkey) -> Result<()> {
+        self.set_inner(Config::new(admin, network_fee_recipient));
         Ok(())
     }
 
     fn update(&mut self, settings: ConfigSettings) -> Result<()> {
+        // Token fees collected by workers cannot be claimed or distributed yet, so the network does not accept them.
+        require!(
+            settings.fee_mint.is_none() && settings.fee_price_feed.is_none(),
+            AntegenNetworkError::TokenFeesUnsupported
+        );
+        require!(
+            settings.min_commission_rate.le(&settings.max_commission_rate)
+                && settings.max_commission_rate.le(&COMMISSION_RATE_LIMIT),
+            AntegenNetworkError::InvalidCommissionRate
+        );
+        require!(
+            settings.network_fee_bps.le(&TOTAL_BASIS_POINTS),
+            AntegenNetworkError::InvalidNetworkFee
+        );
+        require!(
+            Config::cron_schedule(settings.epoch_length).is_some()
+                && Config::cron_schedule(settings.hasher_interval).is_some(),
+            AntegenNetworkError::InvalidThreadSchedule
+        );
+        require!(
+            settings.batch_size.gt(&0) && settings.batch_size.le(&MAX_BATCH_SIZE),
+            AntegenNetworkError::InvalidBatchSize
+        );
         self.admin = settings.admin;
+        self.batch_size = settings.batch_size;
+        self.epoch_length = settings.epoch_length;
+        self.epoch_summaries = settings.epoch_summaries;
         self.epoch_thread = settings.epoch_thread;
+        self.fee_mint = settings.fee_mint;
+        self.fee_price_feed = settings.fee_price_feed;
+        self.hasher_interval = settings.hasher_interval;
         self.hasher_thread = settings.hasher_thread;
+        self.heartbeat_timeout = settings.heartbeat_timeout;
+        self.max_commission_rate = settings.max_commission_rate;
+        self.min_commission_rate = settings.min_commission_rate;
+        self.min_stake = settings.min_stake;
+        self.network_fee_bps = settings.network_fee_bps;
+        self.network_fee_recipient = settings.network_fee_recipient;
+        self.snapshot_retention = settings.snapshot_retention;
+        self.thread_funding = settings.thread_funding;
+        self.timelock = settings.timelock;
+        self.unbonding_period = settings.unbonding_period;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_appends_network_settings() {
+        let legacy_config = LegacyConfig {
+            admin: Pubkey::new_from_array([1; 32]),
+            epoch_thread: Pubkey::new_from_array([2; 32]),
+            hasher_thread: Pubkey::new_from_array([3; 32]),
+        };
+        let legacy_data = legacy_config.try_to_vec().unwrap();
+        let config = Config::from(legacy_config);
+        let data = config.try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the default settings.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(config.network_fee_recipient, ANTEGEN_SQUADS);
+        assert_eq!(config.epoch_length, DEFAULT_EPOCH_LENGTH);
+    }
+
+    #[test]
+    fn commission_bps_requires_the_minimum_snapshot_stake() {
+        let mut config = Config::from(LegacyConfig {
+            admin: Pubkey::default(),
+            epoch_thread: Pubkey::default(),
+            hasher_thread: Pubkey::default(),
+        });
+        config.min_commission_rate = 10;
+        config.max_commission_rate = 50;
+        config.min_stake = 100;
+        assert_eq!(config.commission_bps(20, 99), 0);
+        assert_eq!(config.commission_bps(20, 100), 2_000);
+        assert_eq!(config.commission_bps(80, 100), 5_000);
+    }
+}
@@ -0,0 +1,149 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use crate::errors::*;
+
+pub const SEED_DELEGATION: &[u8] = b"delegation";
+
+/// Escrows the lamports a third party has delegated to a worker, along with the fees it has earned.
+#[account]
+#[derive(Debug)]
+pub struct Delegation {
+    /// The delegator.
+    pub authority: Pubkey,
+    /// The delegation's id, unique per worker.
+    pub id: u64,
+    /// The number of lamports delegated to the worker.
+    pub stake_amount: u64,
+    /// The worker the stake is delegated to.
+    pub worker: Pubkey,
+    /// The number of lamports earned and available to claim.
+    pub yield_balance: u64,
+    /// The number of lamports waiting out the unbonding period.
+    pub unbonding_amount: u64,
+    /// The epoch the unbonding lamports may be withdrawn from.
+    pub unbonds_at: u64,
+}
+
+impl Delegation {
+    pub fn pubkey(worker: Pubkey, id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_DELEGATION,
+                worker.as_ref(),
+                id.to_be_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Reads a delegation from an account that may not have been created.
+    pub fn peek(account_info: &AccountInfo) -> Option<Delegation> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        Delegation::try_deserialize(&mut data.as_ref()).ok()
+    }
+
+    /// Whether the unbonding lamports may be withdrawn in the given epoch.
+    pub fn is_unbonded(&self, epoch: u64) -> bool {
+        epoch >= self.unbonds_at
+    }
+}
+
+/// Trait for reading and writing to a delegation account.
+pub trait DelegationAccount {
+    /// Get the pubkey of the delegation account.
+    fn pubkey(&self) -> Pubkey;
+
+    /// Initialize the account to hold a delegation.
+    fn init(&mut self, authority: Pubkey, id: u64, worker: Pubkey) -> Result<()>;
+
+    /// Move lamports from the delegated stake into the unbonding balance in the given epoch.
+    fn unbond(&mut self, amount: u64, epoch: u64) -> Result<()>;
+}
+
+impl DelegationAccount for Account<'_, Delegation> {
+    fn pubkey(&self) -> Pubkey {
+        Delegation::pubkey(self.worker, self.id)
+    }
+
+    fn init(&mut self, authority: Pubkey, id: u64, worker: Pubkey) -> Result<()> {
+        self.authority = authority;
+        self.id = id;
+        self.stake_amount = 0;
+        self.worker = worker;
+        self.yield_balance = 0;
+        self.unbonding_amount = 0;
+        self.unbonds_at = 0;
+        Ok(())
+    }
+
+    fn unbond(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        require!(
+            amount.gt(&0) && amount.le(&self.stake_amount),
+            AntegenNetworkError::InvalidStakeAmount
+        );
+        self.stake_amount = self.stake_amount.checked_sub(amount).unwrap();
+        self.unbonding_amount = self.unbonding_amount.checked_add(amount).unwrap();
+
+        // The stake stays in the current epoch's snapshot, so it is locked until the epoch closes.
+        // Unbonding more lamports restarts the period.
+        self.unbonds_at = epoch.checked_add(1).unwrap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a closure against a delegation of 100 lamports wrapped in a program-owned account.
+    fn with_account<T>(f: impl FnOnce(&mut Account<Delegation>) -> T) -> T {
+        let delegation = Delegation {
+            authority: Pubkey::new_unique(),
+            id: 0,
+            stake_amount: 100,
+            worker: Pubkey::new_unique(),
+            yield_balance: 0,
+            unbonding_amount: 0,
+            unbonds_at: 0,
+        };
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        delegation.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&mut Account::try_from(&info).unwrap())
+    }
+
+    #[test]
+    fn unbond_locks_the_stake_until_the_epoch_closes() {
+        with_account(|delegation| {
+            delegation.unbond(40, 5).unwrap();
+            assert_eq!((delegation.stake_amount, delegation.unbonding_amount), (60, 40));
+            assert!(!delegation.is_unbonded(5));
+            assert!(delegation.is_unbonded(6));
+        });
+    }
+
+    #[test]
+    fn unbond_restarts_the_period() {
+        with_account(|delegation| {
+            delegation.unbond(40, 5).unwrap();
+            delegation.unbond(10, 7).unwrap();
+            assert_eq!((delegation.stake_amount, delegation.unbonding_amount), (50, 50));
+            assert!(!delegation.is_unbonded(7));
+        });
+    }
+
+    #[test]
+    fn unbond_rejects_more_than_the_stake() {
+        with_account(|delegation| {
+            assert!(delegation.unbond(0, 5).is_err());
+            assert!(delegation.unbond(101, 5).is_err());
+        });
+    }
+}
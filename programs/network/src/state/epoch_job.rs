@@ -0,0 +1,206 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+pub const SEED_EPOCH_JOB: &[u8] = b"epoch_job";
+
+/// Tracks the epoch thread's progress through the jobs closing out an epoch, so a failed run can resume.
+#[account]
+#[derive(Debug)]
+pub struct EpochJob {
+    /// The epoch being closed out.
+    pub epoch: u64,
+    /// The next snapshot entry to process within the current frame, once the frame's worker has been processed.
+    pub next_entry: Option<u64>,
+    /// The index of the next frame, worker or pool to process in the current phase.
+    pub next_index: u64,
+    /// The oldest snapshot which has not been deleted.
+    pub next_snapshot: u64,
+    /// The job in progress.
+    pub phase: EpochPhase,
+    /// The slot progress was last recorded at.
+    pub updated_at: u64,
+}
+
+impl EpochJob {
+    pub fn pubkey() -> Pubkey {
+        Pubkey::find_program_address(&[SEED_EPOCH_JOB], &crate::ID).0
+    }
+}
+
+/// The jobs the epoch thread runs, in order.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochPhase {
+    Idle,
+    DistributeFees,
+    EvictWorkers,
+    TakeSnapshot,
+    IncrementEpoch,
+    DeleteSnapshot,
+}
+
+impl EpochPhase {
+    /// The phase which must complete before this one may begin.
+    pub fn previous(&self) -> EpochPhase {
+        match self {
+            EpochPhase::Idle => EpochPhase::DeleteSnapshot,
+            EpochPhase::DistributeFees => EpochPhase::Idle,
+            EpochPhase::EvictWorkers => EpochPhase::DistributeFees,
+            EpochPhase::TakeSnapshot => EpochPhase::EvictWorkers,
+            EpochPhase::IncrementEpoch => EpochPhase::TakeSnapshot,
+            EpochPhase::DeleteSnapshot => EpochPhase::IncrementEpoch,
+        }
+    }
+}
+
+/// Trait for reading and writing to the epoch job account.
+pub trait EpochJobAccount {
+    /// Initialize the account, starting snapshot deletion from the given snapshot.
+    fn init(&mut self, next_snapshot: u64) -> Result<()>;
+
+    /// Enter a phase, either resuming it or starting it once the previous phase has completed.
+    /// Returns false if the phase has already run, in which case it should be skipped.
+    fn enter(&mut self, phase: EpochPhase, epoch: u64) -> Result<bool>;
+
+    /// Record the next unit of work to process in the current phase.
+    fn progress(&mut self, next_index: u64, next_entry: Option<u64>) -> Result<()>;
+
+    /// Record that the next snapshot has been deleted.
+    fn snapshot_deleted(&mut self) -> Result<()>;
+
+    /// Mark the epoch's jobs as complete.
+    fn complete(&mut self) -> Result<()>;
+}
+
+impl EpochJobAccount for Account<'_, EpochJob> {
+    fn init(&mut self, next_snapshot: u64) -> Result<()> {
+        self.next_snapshot = next_snapshot;
+        self.phase = EpochPhase::Idle;
+        self.progress(0, None)
+    }
+
+    fn enter(&mut self, phase: EpochPhase, epoch: u64) -> Result<bool> {
+        if self.phase.eq(&phase) {
+            return Ok(true);
+        }
+        if self.phase.ne(&phase.previous()) {
+            return Ok(false);
+        }
+        if phase.eq(&EpochPhase::DistributeFees) {
+            self.epoch = epoch;
+        }
+        self.phase = phase;
+        self.progress(0, None)?;
+        Ok(true)
+    }
+
+    fn progress(&mut self, next_index: u64, next_entry: Option<u64>) -> Result<()> {
+        self.next_entry = next_entry;
+        self.next_index = next_index;
+        self.updated_at = Clock::get()?.slot;
+        Ok(())
+    }
+
+    fn snapshot_deleted(&mut self) -> Result<()> {
+        self.next_snapshot = self.next_snapshot.checked_add(1).unwrap();
+        self.progress(0, None)
+    }
+
+    fn complete(&mut self) -> Result<()> {
+        self.phase = EpochPhase::Idle;
+        self.progress(0, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        anchor_lang::solana_program::{
+            entrypoint::SUCCESS,
+            program_stubs::{set_syscall_stubs, SyscallStubs},
+        },
+    };
+
+    const SLOT: u64 = 42;
+
+    /// Serves the clock sysvar read when progress is recorded.
+    struct ClockStubs;
+
+    impl SyscallStubs for ClockStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { slot: SLOT, ..Clock::default() };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            SUCCESS
+        }
+    }
+
+    /// Runs a closure against the job wrapped in a program-owned account.
+    fn with_account<T>(phase: EpochPhase, f: impl FnOnce(&mut Account<EpochJob>) -> T) -> T {
+        set_syscall_stubs(Box::new(ClockStubs));
+        let job = EpochJob {
+            epoch: 1,
+            next_entry: Some(3),
+            next_index: 2,
+            next_snapshot: 0,
+            phase,
+            updated_at: 0,
+        };
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        job.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&mut Account::try_from(&info).unwrap())
+    }
+
+    #[test]
+    fn enter_resumes_current_phase() {
+        with_account(EpochPhase::TakeSnapshot, |job| {
+            assert!(job.enter(EpochPhase::TakeSnapshot, 2).unwrap());
+            assert_eq!((job.next_index, job.next_entry, job.updated_at), (2, Some(3), 0));
+        });
+    }
+
+    #[test]
+    fn enter_starts_next_phase() {
+        with_account(EpochPhase::Idle, |job| {
+            assert!(job.enter(EpochPhase::DistributeFees, 2).unwrap());
+            assert_eq!(job.phase, EpochPhase::DistributeFees);
+            assert_eq!(job.epoch, 2);
+            assert_eq!((job.next_index, job.next_entry, job.updated_at), (0, None, SLOT));
+        });
+    }
+
+    #[test]
+    fn enter_skips_phases_out_of_order() {
+        with_account(EpochPhase::EvictWorkers, |job| {
+            assert!(!job.enter(EpochPhase::DistributeFees, 2).unwrap());
+            assert!(!job.enter(EpochPhase::IncrementEpoch, 2).unwrap());
+            assert_eq!(job.phase, EpochPhase::EvictWorkers);
+        });
+    }
+
+    #[test]
+    fn phases_form_a_cycle() {
+        let mut phase = EpochPhase::Idle;
+        for expected in [
+            EpochPhase::DeleteSnapshot,
+            EpochPhase::IncrementEpoch,
+            EpochPhase::TakeSnapshot,
+            EpochPhase::EvictWorkers,
+            EpochPhase::DistributeFees,
+            EpochPhase::Idle,
+        ] {
+            phase = phase.previous();
+            assert_eq!(phase, expected);
+        }
+    }
+
+    #[test]
+    fn progress_records_the_slot() {
+        with_account(EpochPhase::DeleteSnapshot, |job| {
+            job.progress(5, Some(6)).unwrap();
+            assert_eq!((job.next_index, job.next_entry, job.updated_at), (5, Some(6), SLOT));
+        });
+    }
+}
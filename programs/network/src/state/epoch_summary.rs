@@ -0,0 +1,106 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv, AnchorDeserialize};
+
+use crate::errors::*;
+
+pub const SEED_EPOCH_SUMMARY: &[u8] = b"epoch_summary";
+
+/// The depth of the payouts merkle tree, which holds up to 2^16 - 1 workers.
+pub const PAYOUTS_TREE_DEPTH: usize = 16;
+
+/// A compact record of an epoch's fee distribution, kept after the epoch's snapshot is deleted.
+#[account]
+#[derive(Debug)]
+pub struct EpochSummary {
+    /// The epoch this summary records.
+    pub epoch: u64,
+    /// The last left node at each level of the payouts tree, used to append the next payout.
+    pub payouts_branch: [[u8; 32]; PAYOUTS_TREE_DEPTH],
+    /// The merkle root of the workers' payouts, in snapshot frame order.
+    pub payouts_root: [u8; 32],
+    /// The number of lamports collected in fees across all workers.
+    pub total_fees: u64,
+    /// The number of workers whose payouts have been recorded.
+    pub total_workers: u64,
+}
+
+impl EpochSummary {
+    pub fn pubkey(epoch: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[SEED_EPOCH_SUMMARY, epoch.to_be_bytes().as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// The payouts tree leaf of a worker, committing to the fees it collected, the commission it kept
+    /// and the balance set aside for its delegators.
+    pub fn payout_leaf(worker: Pubkey, fees: u64, commission: u64, distributable: u64) -> [u8; 32] {
+        hashv(&[
+            worker.as_ref(),
+            fees.to_le_bytes().as_ref(),
+            commission.to_le_bytes().as_ref(),
+            distributable.to_le_bytes().as_ref(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// EpochSummaryAccount
+pub trait EpochSummaryAccount {
+    /// Append a worker's payout to the summary and recompute the payouts root.
+    fn record_payout(
+        &mut self,
+        epoch: u64,
+        worker: Pubkey,
+        fees: u64,
+        commission: u64,
+        distributable: u64,
+    ) -> Result<()>;
+}
+
+impl EpochSummaryAccount for Account<'_, EpochSummary> {
+    fn record_payout(
+        &mut self,
+        epoch: u64,
+        worker: Pubkey,
+        fees: u64,
+        commission: u64,
+        distributable: u64,
+    ) -> Result<()> {
+        require!(
+            self.total_workers.lt(&((1 << PAYOUTS_TREE_DEPTH) - 1)),
+            AntegenNetworkError::EpochSummaryFull
+        );
+        self.epoch = epoch;
+        self.total_fees = self.total_fees.checked_add(fees).unwrap();
+        self.total_workers = self.total_workers.checked_add(1).unwrap();
+
+        // Fold the new leaf into the branch until it becomes a left node.
+        let mut node = EpochSummary::payout_leaf(worker, fees, commission, distributable);
+        let mut size = self.total_workers;
+        for level in 0..PAYOUTS_TREE_DEPTH {
+            if size & 1 == 1 {
+                self.payouts_branch[level] = node;
+                break;
+            }
+            node = hashv(&[&self.payouts_branch[level], &node]).to_bytes();
+            size >>= 1;
+        }
+
+        // Recompute the root, padding the tree's unfilled leaves with zeroes.
+        let mut root = [0u8; 32];
+        let mut zero = [0u8; 32];
+        let mut size = self.total_workers;
+        for level in 0..PAYOUTS_TREE_DEPTH {
+            root = if size & 1 == 1 {
+                hashv(&[&self.payouts_branch[level], &root]).to_bytes()
+            } else {
+                hashv(&[&root, &zero]).to_bytes()
+            };
+            zero = hashv(&[&zero, &zero]).to_bytes();
+            size >>= 1;
+        }
+        self.payouts_root = root;
+        Ok(())
+    }
+}
@@ -1,15 +1,31 @@
 mod config;
+mod delegation;
+mod epoch_job;
+mod epoch_summary;
 mod worker_commission;
 mod pool;
+mod proposal;
 mod registry;
+mod registry_stats;
 mod snapshot;
+mod snapshot_entry;
 mod snapshot_frame;
 mod worker;
+mod worker_stake;
+mod worker_stats;
 
 pub use config::*;
+pub use delegation::*;
+pub use epoch_job::*;
+pub use epoch_summary::*;
 pub use worker_commission::*;
 pub use pool::*;
+pub use proposal::*;
 pub use registry::*;
+pub use registry_stats::*;
 pub use snapshot::*;
+pub use snapshot_entry::*;
 pub use snapshot_frame::*;
 pub use worker::*;
+pub use worker_stake::*;
+pub use worker_stats::*;
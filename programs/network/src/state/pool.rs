@@ -1,9 +1,22 @@
-use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_lang::{prelude::*, solana_program::hash::hashv, AnchorDeserialize};
 
 pub const SEED_POOL: &[u8] = b"pool";
 
 const DEFAULT_POOL_SIZE: u64 = 1;
 
+/// The default number of slots between rotations of a full pool (roughly 10 minutes).
+const DEFAULT_POOL_ROTATION_PERIOD: u64 = 1_500;
+
+/// The number of slots a thread's assignment to a pool member holds for.
+pub const THREAD_ASSIGNMENT_WINDOW: u64 = 20;
+
+/// The number of slots the assigned worker has to process a due thread before other workers may take over.
+pub const THREAD_ASSIGNMENT_GRACE_PERIOD: u64 = 16;
+
+/// The number of slots a due thread is left to the pool's members before workers outside the pool may process it.
+/// This exceeds the grace period given to a thread's assigned worker.
+pub const THREAD_TIMEOUT_WINDOW: u64 = 24;
+
 /**
  * Pool
  */
@@ -14,20 +27,112 @@ pub struct Pool {
     pub id: u64,
     pub size: u64,
     pub workers: Vec<Pubkey>,
+    /// The slot the pool last admitted a worker at.
+    pub rotated_at: u64,
+    /// The number of slots a full pool waits between rotations.
+    pub rotation_period: u64,
 }
 
 impl Pool {
     pub fn pubkey(id: u64) -> Pubkey {
         Pubkey::find_program_address(&[SEED_POOL, id.to_be_bytes().as_ref()], &crate::ID).0
     }
+
+    /// Reads a pool from an account that may not have been created.
+    pub fn peek(account_info: &AccountInfo) -> Option<Pool> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        Pool::try_deserialize(&mut data.as_ref()).ok()
+    }
+
+    /// Whether the pool has an empty seat any worker may take.
+    pub fn has_space(&self) -> bool {
+        self.workers.len() < (self.size as usize)
+    }
+
+    /// The pool member assigned to process a thread which became due at the given slot.
+    /// Assignments are derived from the thread, the slot's assignment window and the registry nonce.
+    pub fn assigned_worker(&self, thread: Pubkey, due_slot: u64, nonce: u64) -> Option<Pubkey> {
+        if self.workers.is_empty() {
+            return None;
+        }
+        let hash = hashv(&[
+            thread.as_ref(),
+            &(due_slot / THREAD_ASSIGNMENT_WINDOW).to_le_bytes(),
+            &nonce.to_le_bytes(),
+        ]);
+        let seed = u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap());
+        let index = seed % (self.workers.len() as u64);
+        self.workers.get(index as usize).copied()
+    }
+
+    /// Whether a worker may process a thread which became due at `due_slot`.
+    /// Only the assigned worker may until the grace period has passed, after which any other pool member may take over.
+    pub fn is_assigned(
+        &self,
+        worker: Pubkey,
+        thread: Pubkey,
+        due_slot: u64,
+        nonce: u64,
+        slot: u64,
+    ) -> bool {
+        match self.assigned_worker(thread, due_slot, nonce) {
+            None => true,
+            Some(assigned_worker) => {
+                assigned_worker.eq(&worker)
+                    || (slot >= due_slot.saturating_add(THREAD_ASSIGNMENT_GRACE_PERIOD)
+                        && self.workers.contains(&worker))
+            }
+        }
+    }
+
+    /// Whether a worker may process the pool's threads which became due at `due_slot`.
+    /// Members always may, while workers outside a non-empty pool must wait out the timeout window.
+    pub fn admits(&self, worker: Pubkey, due_slot: u64, slot: u64) -> bool {
+        self.workers.is_empty()
+            || self.workers.contains(&worker)
+            || slot > due_slot.saturating_add(THREAD_TIMEOUT_WINDOW)
+    }
+
+    /// Whether a full pool may rotate at the given slot.
+    pub fn is_rotation_due(&self, slot: u64) -> bool {
+        slot >= self.rotated_at.saturating_add(self.rotation_period)
+    }
+}
+
+/**
+ * LegacyPool
+ */
+
+/// The layout of pools created before rotation settings were appended, read by `pool_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacyPool {
+    pub id: u64,
+    pub size: u64,
+    pub workers: Vec<Pubkey>,
+}
+
+impl From<LegacyPool> for Pool {
+    fn from(legacy: LegacyPool) -> Self {
+        Pool {
+            id: legacy.id,
+            size: legacy.size,
+            workers: legacy.workers,
+            rotated_at: 0,
+            rotation_period: DEFAULT_POOL_ROTATION_PERIOD,
+        }
+    }
 }
 
 /**
  * PoolSettings
  */
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct PoolSettings {
+    pub rotation_period: u64,
     pub size: u64,
 }
 
@@ -40,7 +145,7 @@ pub trait PoolAccount {
 
     fn init(&mut self, id: u64) -> Result<()>;
 
-    fn rotate(&mut self, worker: Pubkey) -> Result<()>;
+    fn rotate(&mut self, worker: Pubkey, slot: u64) -> Result<()>;
 
     fn update(&mut self, settings: &PoolSettings) -> Result<()>;
 }
@@ -52,22 +157,27 @@ impl PoolAccount for Account<'_, Pool> {
 
     fn init(&mut self, id: u64) -> Result<()> {
         self.id = id;
+        self.rotated_at = 0;
+        self.rotation_period = DEFAULT_POOL_ROTATION_PERIOD;
         self.size = DEFAULT_POOL_SIZE;
         self.workers = Vec::new();
         Ok(())
     }
 
-    fn rotate(&mut self, worker: Pubkey) -> Result<()> {
-        let old_size = self.size;
+    fn rotate(&mut self, worker: Pubkey, slot: u64) -> Result<()> {
+        // If the pool is full, evict the longest-seated worker.
+        if !self.has_space() && !self.workers.is_empty() {
+            self.workers.remove(0);
+        }
         // Push new worker into the pool.
         self.workers.push(worker);
-        // Drain pool to the configured size limit.
-        self.workers.truncate(old_size as usize);
+        self.rotated_at = slot;
         Ok(())
     }
 
     fn update(&mut self, settings: &PoolSettings) -> Result<()> {
         let new_size = settings.size;  // Store the size locally first
+        self.rotation_period = settings.rotation_period;
         self.size = new_size; 
 
         // Drain pool to the configured size limit.
@@ -75,3 +185,129 @@ impl PoolAccount for Account<'_, Pool> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(workers: Vec<Pubkey>) -> Pool {
+        Pool {
+            id: 0,
+            size: workers.len() as u64,
+            workers,
+            rotated_at: 0,
+            rotation_period: DEFAULT_POOL_ROTATION_PERIOD,
+        }
+    }
+
+    fn worker(i: u8) -> Pubkey {
+        Pubkey::new_from_array([i; 32])
+    }
+
+    /// Runs a closure against the pool wrapped in a program-owned account.
+    fn with_account<T>(pool: Pool, f: impl FnOnce(&mut Account<Pool>) -> T) -> T {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&mut Account::try_from(&info).unwrap())
+    }
+
+    #[test]
+    fn assigned_worker_is_pinned() {
+        let pool = pool(vec![worker(1), worker(2), worker(3)]);
+        let thread = Pubkey::new_from_array([9; 32]);
+        let assignments: Vec<Pubkey> = (0..4)
+            .map(|window| {
+                pool.assigned_worker(thread, window * THREAD_ASSIGNMENT_WINDOW, 7)
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(assignments, vec![worker(2), worker(1), worker(1), worker(1)]);
+    }
+
+    #[test]
+    fn assigned_worker_holds_for_the_window() {
+        let pool = pool(vec![worker(1), worker(2), worker(3)]);
+        let thread = Pubkey::new_from_array([9; 32]);
+        let assigned_worker = pool.assigned_worker(thread, 0, 7);
+        for slot in 1..THREAD_ASSIGNMENT_WINDOW {
+            assert_eq!(pool.assigned_worker(thread, slot, 7), assigned_worker);
+        }
+    }
+
+    #[test]
+    fn assigned_worker_of_empty_pool() {
+        assert_eq!(pool(vec![]).assigned_worker(Pubkey::default(), 0, 0), None);
+    }
+
+    #[test]
+    fn is_assigned_falls_back_to_pool_members() {
+        let pool = pool(vec![worker(1), worker(2), worker(3)]);
+        let thread = Pubkey::new_from_array([9; 32]);
+        let due_slot = 100;
+        let grace_slot = due_slot + THREAD_ASSIGNMENT_GRACE_PERIOD;
+        let assigned_worker = pool.assigned_worker(thread, due_slot, 7).unwrap();
+        let other_member = *pool.workers.iter().find(|w| w.ne(&&assigned_worker)).unwrap();
+        let outsider = worker(4);
+
+        // Only the assigned worker may process the thread during the grace period.
+        assert!(pool.is_assigned(assigned_worker, thread, due_slot, 7, due_slot));
+        assert!(!pool.is_assigned(other_member, thread, due_slot, 7, grace_slot - 1));
+        assert!(!pool.is_assigned(outsider, thread, due_slot, 7, grace_slot - 1));
+
+        // Other pool members may take over once it has passed, but workers outside the pool may not.
+        assert!(pool.is_assigned(other_member, thread, due_slot, 7, grace_slot));
+        assert!(!pool.is_assigned(outsider, thread, due_slot, 7, grace_slot + 1_000));
+    }
+
+    #[test]
+    fn is_assigned_in_empty_pool() {
+        assert!(pool(vec![]).is_assigned(worker(1), Pubkey::default(), 0, 0, 0));
+    }
+
+    #[test]
+    fn rotate_fills_open_seats() {
+        let pool = Pool { size: 2, ..pool(vec![worker(1)]) };
+        with_account(pool, |pool| {
+            pool.rotate(worker(2), 50).unwrap();
+            assert_eq!(pool.workers, vec![worker(1), worker(2)]);
+            assert_eq!(pool.rotated_at, 50);
+        });
+    }
+
+    #[test]
+    fn rotate_evicts_longest_seated_worker() {
+        with_account(pool(vec![worker(1), worker(2)]), |pool| {
+            assert!(!pool.is_rotation_due(DEFAULT_POOL_ROTATION_PERIOD - 1));
+            assert!(pool.is_rotation_due(DEFAULT_POOL_ROTATION_PERIOD));
+            pool.rotate(worker(3), DEFAULT_POOL_ROTATION_PERIOD).unwrap();
+            assert_eq!(pool.workers, vec![worker(2), worker(3)]);
+            assert!(!pool.is_rotation_due(DEFAULT_POOL_ROTATION_PERIOD * 2 - 1));
+        });
+    }
+
+    #[test]
+    fn layout_appends_rotation_settings() {
+        let legacy_pool = LegacyPool { id: 3, size: 2, workers: vec![worker(1), worker(2)] };
+        let legacy_data = legacy_pool.try_to_vec().unwrap();
+        let pool = Pool::from(legacy_pool);
+        let data = pool.try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the rotation settings.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(data.len(), legacy_data.len() + 16);
+        assert_eq!((pool.rotated_at, pool.rotation_period), (0, DEFAULT_POOL_ROTATION_PERIOD));
+    }
+
+    #[test]
+    fn admits_outsiders_after_timeout_window() {
+        let pool = pool(vec![worker(1)]);
+        let due_slot = 100;
+        assert!(pool.admits(worker(1), due_slot, due_slot));
+        assert!(!pool.admits(worker(2), due_slot, due_slot + THREAD_TIMEOUT_WINDOW));
+        assert!(pool.admits(worker(2), due_slot, due_slot + THREAD_TIMEOUT_WINDOW + 1));
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use crate::state::{ConfigSettings, PoolSettings};
+
+pub const SEED_PROPOSAL: &[u8] = b"proposal";
+
+/// A pending admin change, executable once the network's timelock has elapsed.
+#[account]
+#[derive(Debug)]
+pub struct Proposal {
+    /// The change to apply.
+    pub action: ProposalAction,
+    /// The slot at which the proposal may be executed.
+    pub executable_at: u64,
+    /// The proposal's id.
+    pub id: u64,
+    /// The admin which made the proposal, refunded when it is executed or cancelled.
+    pub proposer: Pubkey,
+}
+
+impl Proposal {
+    pub fn pubkey(id: u64) -> Pubkey {
+        Pubkey::find_program_address(&[SEED_PROPOSAL, id.to_be_bytes().as_ref()], &crate::ID).0
+    }
+}
+
+/// The admin actions which may be proposed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum ProposalAction {
+    ConfigUpdate { settings: ConfigSettings },
+    PoolUpdate { pool_id: u64, settings: PoolSettings },
+    RegistryReset,
+    RegistryUnlock,
+    WorkerSlash { worker: Pubkey, amount: u64 },
+}
+
+/// Trait for reading and writing to a proposal account.
+pub trait ProposalAccount {
+    fn pubkey(&self) -> Pubkey;
+
+    fn init(
+        &mut self,
+        action: ProposalAction,
+        executable_at: u64,
+        id: u64,
+        proposer: Pubkey,
+    ) -> Result<()>;
+}
+
+impl ProposalAccount for Account<'_, Proposal> {
+    fn pubkey(&self) -> Pubkey {
+        Proposal::pubkey(self.id)
+    }
+
+    fn init(
+        &mut self,
+        action: ProposalAction,
+        executable_at: u64,
+        id: u64,
+        proposer: Pubkey,
+    ) -> Result<()> {
+        self.action = action;
+        self.executable_at = executable_at;
+        self.id = id;
+        self.proposer = proposer;
+        Ok(())
+    }
+}
@@ -26,6 +26,13 @@ impl Registry {
         )
         .0
     }
+
+    /// Whether the current nonce selects the given worker for the next seat in a full pool.
+    pub fn is_selected(&self, worker_id: u64) -> bool {
+        self.nonce != 0
+            && self.total_workers > 0
+            && self.nonce % self.total_workers == worker_id
+    }
 }
 
 /**
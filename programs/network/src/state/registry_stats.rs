@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use crate::state::WorkerActivity;
+
+pub const SEED_REGISTRY_STATS: &[u8] = b"registry_stats";
+
+/// Aggregates the work performed across the whole network. The epoch job adds up each worker's
+/// statistics as it snapshots them, so the latest epoch is the one most recently closed.
+#[account]
+#[derive(Debug)]
+pub struct RegistryStats {
+    /// The epoch the epoch statistics were recorded in.
+    pub epoch: u64,
+    /// The number of thread execs processed in the epoch.
+    pub epoch_execs: u64,
+    /// The number of lamports paid to workers in fees in the epoch.
+    pub epoch_fees: u64,
+    /// The number of thread kickoffs processed in the epoch.
+    pub epoch_kickoffs: u64,
+    /// The number of thread execs processed since the statistics were created.
+    pub total_execs: u64,
+    /// The number of lamports paid to workers in fees since the statistics were created.
+    pub total_fees: u64,
+    /// The number of thread kickoffs processed since the statistics were created.
+    pub total_kickoffs: u64,
+}
+
+impl RegistryStats {
+    pub fn pubkey() -> Pubkey {
+        Pubkey::find_program_address(&[SEED_REGISTRY_STATS], &crate::ID).0
+    }
+}
+
+/// Trait for reading and writing to the registry's stats account.
+pub trait RegistryStatsAccount {
+    /// Add a worker's activity in the given epoch, resetting the epoch statistics if a new epoch has begun.
+    fn record(&mut self, epoch: u64, activity: WorkerActivity) -> Result<()>;
+}
+
+impl RegistryStatsAccount for Account<'_, RegistryStats> {
+    fn record(&mut self, epoch: u64, activity: WorkerActivity) -> Result<()> {
+        if self.epoch.ne(&epoch) {
+            self.epoch = epoch;
+            self.epoch_execs = 0;
+            self.epoch_fees = 0;
+            self.epoch_kickoffs = 0;
+        }
+
+        self.epoch_execs = self.epoch_execs.checked_add(activity.execs).unwrap();
+        self.epoch_fees = self.epoch_fees.checked_add(activity.fees).unwrap();
+        self.epoch_kickoffs = self.epoch_kickoffs.checked_add(activity.kickoffs).unwrap();
+        self.total_execs = self.total_execs.checked_add(activity.execs).unwrap();
+        self.total_fees = self.total_fees.checked_add(activity.fees).unwrap();
+        self.total_kickoffs = self.total_kickoffs.checked_add(activity.kickoffs).unwrap();
+        Ok(())
+    }
+}
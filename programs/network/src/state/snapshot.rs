@@ -8,6 +8,8 @@ pub const SEED_SNAPSHOT: &[u8] = b"snapshot";
 pub struct Snapshot {
     pub id: u64,
     pub total_frames: u64,
+    /// The id of the next worker to visit while the snapshot is being taken.
+    pub next_worker_id: u64,
 }
 
 impl Snapshot {
@@ -16,6 +18,24 @@ impl Snapshot {
     }
 }
 
+/// The layout of snapshots taken before worker visits were tracked, read by `snapshot_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacySnapshot {
+    pub id: u64,
+    pub total_frames: u64,
+}
+
+impl From<LegacySnapshot> for Snapshot {
+    fn from(legacy: LegacySnapshot) -> Self {
+        Snapshot {
+            id: legacy.id,
+            total_frames: legacy.total_frames,
+            // Legacy snapshots took one frame per worker, in id order.
+            next_worker_id: legacy.total_frames,
+        }
+    }
+}
+
 /// SnapshotAccount
 pub trait SnapshotAccount {
     fn pubkey(&self) -> Pubkey;
@@ -31,6 +51,25 @@ impl SnapshotAccount for Account<'_, Snapshot> {
     fn init(&mut self, id: u64) -> Result<()> {
         self.id = id;
         self.total_frames = 0;
+        self.next_worker_id = 0;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_appends_next_worker_id() {
+        let legacy_snapshot = LegacySnapshot { id: 4, total_frames: 9 };
+        let legacy_data = legacy_snapshot.try_to_vec().unwrap();
+        let snapshot = Snapshot::from(legacy_snapshot);
+        let data = snapshot.try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the next worker to visit.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(data.len(), legacy_data.len() + 8);
+        assert_eq!(snapshot.next_worker_id, 9);
+    }
+}
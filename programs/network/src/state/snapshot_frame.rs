@@ -1,5 +1,7 @@
 use anchor_lang::{prelude::*, AnchorDeserialize};
 
+use crate::state::WorkerActivity;
+
 pub const SEED_SNAPSHOT_FRAME: &[u8] = b"snapshot_frame";
 
 /**
@@ -11,6 +13,13 @@ pub struct SnapshotFrame {
     pub id: u64,
     pub snapshot: Pubkey,
     pub worker: Pubkey,
+    pub stake: u64,
+    pub delegated_stake: u64,
+    pub total_entries: u64,
+    pub execs: u64,
+    pub fees: u64,
+    pub kickoffs: u64,
+    pub reimbursements: u64,
 }
 
 impl SnapshotFrame {
@@ -25,6 +34,44 @@ impl SnapshotFrame {
         )
         .0
     }
+
+    /// Reads a snapshot frame from an account that may not exist.
+    pub fn peek(account_info: &AccountInfo) -> Option<SnapshotFrame> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        SnapshotFrame::try_deserialize(&mut data.as_ref()).ok()
+    }
+}
+
+/**
+ * LegacySnapshotFrame
+ */
+
+/// The layout of frames taken before stake and activity were recorded, read by `snapshot_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacySnapshotFrame {
+    pub id: u64,
+    pub snapshot: Pubkey,
+    pub worker: Pubkey,
+}
+
+impl From<LegacySnapshotFrame> for SnapshotFrame {
+    fn from(legacy: LegacySnapshotFrame) -> Self {
+        SnapshotFrame {
+            id: legacy.id,
+            snapshot: legacy.snapshot,
+            worker: legacy.worker,
+            stake: 0,
+            delegated_stake: 0,
+            total_entries: 0,
+            execs: 0,
+            fees: 0,
+            kickoffs: 0,
+            reimbursements: 0,
+        }
+    }
 }
 
 /**
@@ -36,8 +83,10 @@ pub trait SnapshotFrameAccount {
 
     fn init(
         &mut self,
+        activity: WorkerActivity,
         id: u64,
         snapshot: Pubkey,
+        stake: u64,
         worker: Pubkey,
     ) -> Result<()>;
 }
@@ -49,13 +98,42 @@ impl SnapshotFrameAccount for Account<'_, SnapshotFrame> {
 
     fn init(
         &mut self,
+        activity: WorkerActivity,
         id: u64,
         snapshot: Pubkey,
+        stake: u64,
         worker: Pubkey,
     ) -> Result<()> {
         self.id = id;
         self.snapshot = snapshot;
         self.worker = worker;
+        self.stake = stake;
+        self.delegated_stake = 0;
+        self.total_entries = 0;
+        self.execs = activity.execs;
+        self.fees = activity.fees;
+        self.kickoffs = activity.kickoffs;
+        self.reimbursements = activity.reimbursements;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_appends_stake_and_activity() {
+        let legacy_frame = LegacySnapshotFrame {
+            id: 2,
+            snapshot: Pubkey::new_from_array([1; 32]),
+            worker: Pubkey::new_from_array([2; 32]),
+        };
+        let legacy_data = legacy_frame.try_to_vec().unwrap();
+        let data = SnapshotFrame::from(legacy_frame).try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the zeroed stake and activity.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(&data[legacy_data.len()..], [0; 56]);
+    }
+}
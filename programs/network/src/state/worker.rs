@@ -1,33 +1,114 @@
 use anchor_lang::{prelude::*, AnchorDeserialize};
 
-use crate::errors::*;
+use crate::{errors::*, state::Config};
 
 pub const SEED_WORKER: &[u8] = b"worker";
+/// The default upper bound of the commission rate workers may set.
 pub const MAX_COMMISSION_RATE: u64 = 90;
+/// The maximum length in bytes of a worker's name.
+pub const MAX_WORKER_NAME_LEN: usize = 32;
+/// The maximum length in bytes of a worker's website and contact.
+pub const MAX_WORKER_URL_LEN: usize = 64;
+/// The maximum length in bytes of a worker's plugin version and region.
+pub const MAX_WORKER_TAG_LEN: usize = 16;
+
 /// Worker
 #[account]
 #[derive(Debug)]
 pub struct Worker {
     /// The worker's authority (owner).
     pub authority: Pubkey,
-    /// Integer between 0 and MAX_COMMISSION_RATE determining the percentage of fees worker will keep as commission.
+    /// Integer within the network's configured commission range determining the percentage of fees worker will keep as commission.
     pub commission_rate: u64,
     /// The worker's id.
     pub id: u64,
     /// The worker's signatory address (used to sign txs).
-    pub signatory: Pubkey
+    pub signatory: Pubkey,
+    /// The number of delegations created for this worker.
+    pub total_delegations: u64,
+    /// The epoch the worker was deactivated in, if it has exited the network.
+    pub deactivated_at: Option<u64>,
+    /// Optional details the operator publishes so thread owners can see who runs their automation.
+    pub metadata: WorkerMetadata,
 }
 
 impl Worker {
     pub fn pubkey(id: u64) -> Pubkey {
         Pubkey::find_program_address(&[SEED_WORKER, id.to_be_bytes().as_ref()], &crate::ID).0
     }
+
+    /// Reads a worker from an account that may have been closed.
+    pub fn peek(account_info: &AccountInfo) -> Option<Worker> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        Worker::try_deserialize(&mut data.as_ref()).ok()
+    }
+
+    /// Whether the worker may join pools and be snapshotted.
+    pub fn is_active(&self) -> bool {
+        self.deactivated_at.is_none()
+    }
+}
+
+/// The layout of workers created before delegations were introduced, read by `worker_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacyWorker {
+    pub authority: Pubkey,
+    pub commission_rate: u64,
+    pub id: u64,
+    pub signatory: Pubkey,
+}
+
+impl From<LegacyWorker> for Worker {
+    fn from(legacy: LegacyWorker) -> Self {
+        Worker {
+            authority: legacy.authority,
+            commission_rate: legacy.commission_rate,
+            deactivated_at: None,
+            id: legacy.id,
+            metadata: WorkerMetadata::default(),
+            signatory: legacy.signatory,
+            total_delegations: 0,
+        }
+    }
+}
+
+/// WorkerMetadata
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct WorkerMetadata {
+    /// How to reach the operator, e.g. an email address or a chat handle.
+    pub contact: Option<String>,
+    /// The operator's display name.
+    pub name: Option<String>,
+    /// The version of the plugin the worker runs.
+    pub plugin_version: Option<String>,
+    /// The region the worker's validator is hosted in.
+    pub region: Option<String>,
+    /// The operator's website.
+    pub website: Option<String>,
+}
+
+impl WorkerMetadata {
+    /// Whether every field is within its length limit.
+    pub fn is_valid(&self) -> bool {
+        let fits = |field: &Option<String>, max_len: usize| {
+            field.as_ref().map(String::len).unwrap_or_default().le(&max_len)
+        };
+        fits(&self.contact, MAX_WORKER_URL_LEN)
+            && fits(&self.name, MAX_WORKER_NAME_LEN)
+            && fits(&self.plugin_version, MAX_WORKER_TAG_LEN)
+            && fits(&self.region, MAX_WORKER_TAG_LEN)
+            && fits(&self.website, MAX_WORKER_URL_LEN)
+    }
 }
 
 /// WorkerSettings
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct WorkerSettings {
     pub commission_rate: u64,
+    pub metadata: WorkerMetadata,
     pub signatory: Pubkey,
 }
 
@@ -37,7 +118,7 @@ pub trait WorkerAccount {
 
     fn init(&mut self, authority: &mut Signer, id: u64, signatory: &Signer) -> Result<()>;
 
-    fn update(&mut self, settings: WorkerSettings) -> Result<()>;
+    fn update(&mut self, config: &Config, settings: WorkerSettings) -> Result<()>;
 }
 
 impl WorkerAccount for Account<'_, Worker> {
@@ -48,18 +129,28 @@ impl WorkerAccount for Account<'_, Worker> {
     fn init(&mut self, authority: &mut Signer, id: u64, signatory: &Signer) -> Result<()> {
         self.authority = authority.key();
         self.commission_rate = MAX_COMMISSION_RATE;
+        self.deactivated_at = None;
         self.id = id;
+        self.metadata = WorkerMetadata::default();
         self.signatory = signatory.key();
+        self.total_delegations = 0;
         Ok(())
     }
 
-    fn update(&mut self, settings: WorkerSettings) -> Result<()> {
+    fn update(&mut self, config: &Config, settings: WorkerSettings) -> Result<()> {
         require!(
-            settings.commission_rate.ge(&0) && settings.commission_rate.le(&MAX_COMMISSION_RATE),
+            settings.commission_rate.ge(&config.min_commission_rate)
+                && settings.commission_rate.le(&config.max_commission_rate),
             AntegenNetworkError::InvalidCommissionRate
         );
         self.commission_rate = settings.commission_rate;
 
+        require!(
+            settings.metadata.is_valid(),
+            AntegenNetworkError::InvalidWorkerMetadata
+        );
+        self.metadata = settings.metadata;
+
         require!(
             settings.signatory.ne(&self.authority),
             AntegenNetworkError::InvalidSignatory
@@ -68,3 +159,26 @@ impl WorkerAccount for Account<'_, Worker> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_appends_delegation_fields() {
+        let legacy_worker = LegacyWorker {
+            authority: Pubkey::new_from_array([1; 32]),
+            commission_rate: 10,
+            id: 3,
+            signatory: Pubkey::new_from_array([2; 32]),
+        };
+        let legacy_data = legacy_worker.try_to_vec().unwrap();
+        let worker = Worker::from(legacy_worker);
+        let data = worker.try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the delegation count, deactivation epoch and metadata.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(&data[legacy_data.len()..], [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(worker.is_active());
+    }
+}
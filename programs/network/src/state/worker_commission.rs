@@ -7,7 +7,11 @@ pub const SEED_WORKER_COMMISSION: &[u8] = b"commission";
 #[derive(Debug)]
 pub struct WorkerCommission {
     pub bump: u8,
-    pub worker: Pubkey
+    pub worker: Pubkey,
+    /// The lamports set aside for the worker's delegators in the current fee distribution.
+    pub distributable_balance: u64,
+    /// The lamports split off for the worker's delegators by claims, paid out in the next fee distribution.
+    pub delegator_balance: u64,
 }
 
 impl WorkerCommission {
@@ -23,6 +27,24 @@ impl WorkerCommission {
     }
 }
 
+/// The layout of commission accounts created before delegator balances were appended, read by `worker_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct LegacyWorkerCommission {
+    pub bump: u8,
+    pub worker: Pubkey,
+}
+
+impl From<LegacyWorkerCommission> for WorkerCommission {
+    fn from(legacy: LegacyWorkerCommission) -> Self {
+        WorkerCommission {
+            bump: legacy.bump,
+            worker: legacy.worker,
+            distributable_balance: 0,
+            delegator_balance: 0,
+        }
+    }
+}
+
 /// Trait for reading and writing to a fee account.
 pub trait WorkerCommissionAccount {
     /// Get the pubkey of the fee account.
@@ -38,7 +60,28 @@ impl WorkerCommissionAccount for Account<'_, WorkerCommission> {
     }
 
     fn init(&mut self, worker: Pubkey) -> Result<()> {
+        self.delegator_balance = 0;
+        self.distributable_balance = 0;
         self.worker = worker;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_appends_delegator_balances() {
+        let legacy_commission = LegacyWorkerCommission {
+            bump: 255,
+            worker: Pubkey::new_from_array([1; 32]),
+        };
+        let legacy_data = legacy_commission.try_to_vec().unwrap();
+        let data = WorkerCommission::from(legacy_commission).try_to_vec().unwrap();
+
+        // The legacy fields keep their offsets, followed by the zeroed balances.
+        assert_eq!(&data[..legacy_data.len()], legacy_data.as_slice());
+        assert_eq!(&data[legacy_data.len()..], [0; 16]);
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use crate::errors::*;
+
+pub const SEED_WORKER_STAKE: &[u8] = b"worker_stake";
+
+/// Escrows the lamports a worker has bonded to the network.
+#[account]
+#[derive(Debug)]
+pub struct WorkerStake {
+    /// The number of lamports currently bonded.
+    pub amount: u64,
+    /// The number of lamports waiting out the unbonding period.
+    pub unbonding_amount: u64,
+    /// The slot the most recent unbonding request was made at.
+    pub unbonding_at: u64,
+    /// The worker this stake is bonded to.
+    pub worker: Pubkey,
+}
+
+impl WorkerStake {
+    pub fn pubkey(worker: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_WORKER_STAKE,
+                worker.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Reads a worker's stake from an account that may not have been created.
+    pub fn peek(account_info: &AccountInfo) -> Option<WorkerStake> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        WorkerStake::try_deserialize(&mut data.as_ref()).ok()
+    }
+
+    /// Whether the unbonding lamports may be withdrawn at the given slot.
+    pub fn is_unbonded(&self, slot: u64, unbonding_period: u64) -> bool {
+        slot >= self.unbonding_at.saturating_add(unbonding_period)
+    }
+}
+
+/// Trait for reading and writing to a stake account.
+pub trait WorkerStakeAccount {
+    /// Get the pubkey of the stake account.
+    fn pubkey(&self) -> Pubkey;
+
+    /// Initialize the account to hold a worker's stake.
+    fn init(&mut self, worker: Pubkey) -> Result<()>;
+
+    /// Whether the account has been initialized for a worker.
+    fn is_initialized(&self) -> bool;
+
+    /// Move lamports from the bonded balance into the unbonding balance.
+    fn unbond(&mut self, amount: u64, slot: u64) -> Result<()>;
+
+    /// Remove lamports from the stake, drawing on the bonded balance first.
+    fn slash(&mut self, amount: u64) -> Result<()>;
+}
+
+impl WorkerStakeAccount for Account<'_, WorkerStake> {
+    fn pubkey(&self) -> Pubkey {
+        WorkerStake::pubkey(self.worker)
+    }
+
+    fn init(&mut self, worker: Pubkey) -> Result<()> {
+        self.amount = 0;
+        self.unbonding_amount = 0;
+        self.unbonding_at = 0;
+        self.worker = worker;
+        Ok(())
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.worker.ne(&Pubkey::default())
+    }
+
+    fn unbond(&mut self, amount: u64, slot: u64) -> Result<()> {
+        require!(
+            amount.gt(&0) && amount.le(&self.amount),
+            AntegenNetworkError::InvalidStakeAmount
+        );
+        self.amount = self.amount.checked_sub(amount).unwrap();
+        self.unbonding_amount = self.unbonding_amount.checked_add(amount).unwrap();
+        self.unbonding_at = slot;
+        Ok(())
+    }
+
+    fn slash(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount.gt(&0)
+                && amount.le(&self.amount.checked_add(self.unbonding_amount).unwrap()),
+            AntegenNetworkError::InvalidStakeAmount
+        );
+        let from_bonded = amount.min(self.amount);
+        self.amount = self.amount.checked_sub(from_bonded).unwrap();
+        self.unbonding_amount = self
+            .unbonding_amount
+            .checked_sub(amount.checked_sub(from_bonded).unwrap())
+            .unwrap();
+        Ok(())
+    }
+}
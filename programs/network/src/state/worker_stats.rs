@@ -0,0 +1,164 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+pub const SEED_WORKER_STATS: &[u8] = b"worker_stats";
+
+/// Tracks the work a worker has performed in the current epoch.
+#[account]
+#[derive(Debug)]
+pub struct WorkerStats {
+    /// The epoch these statistics were recorded in.
+    pub epoch: u64,
+    /// The number of thread execs processed by the worker.
+    pub execs: u64,
+    /// The number of lamports earned in fees.
+    pub fees: u64,
+    /// The slot of the worker's latest heartbeat.
+    pub heartbeat_at: u64,
+    /// The number of thread kickoffs processed by the worker.
+    pub kickoffs: u64,
+    /// The number of lamports reimbursed to the worker's signatory.
+    pub reimbursements: u64,
+    /// The worker these statistics belong to.
+    pub worker: Pubkey,
+}
+
+impl WorkerStats {
+    pub fn pubkey(worker: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_WORKER_STATS,
+                worker.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Reads a worker's statistics from an account that may not have been created.
+    pub fn peek(account_info: &AccountInfo) -> Option<WorkerStats> {
+        if account_info.owner.ne(&crate::ID) {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        WorkerStats::try_deserialize(&mut data.as_ref()).ok()
+    }
+
+    /// The activity recorded in the given epoch.
+    pub fn activity(&self, epoch: u64) -> WorkerActivity {
+        if self.epoch.ne(&epoch) {
+            return WorkerActivity::default();
+        }
+        WorkerActivity {
+            execs: self.execs,
+            fees: self.fees,
+            kickoffs: self.kickoffs,
+            reimbursements: self.reimbursements,
+        }
+    }
+
+    /// Whether the worker has gone longer than the timeout without a heartbeat. A zero timeout never expires.
+    pub fn is_stale(&self, slot: u64, heartbeat_timeout: u64) -> bool {
+        heartbeat_timeout.gt(&0) && slot.saturating_sub(self.heartbeat_at).gt(&heartbeat_timeout)
+    }
+}
+
+/// A unit of work reported by the thread program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorkerActivity {
+    pub execs: u64,
+    pub fees: u64,
+    pub kickoffs: u64,
+    pub reimbursements: u64,
+}
+
+/// Trait for reading and writing to a stats account.
+pub trait WorkerStatsAccount {
+    /// Get the pubkey of the stats account.
+    fn pubkey(&self) -> Pubkey;
+
+    /// Record a heartbeat from the worker at the given slot.
+    fn heartbeat(&mut self, slot: u64) -> Result<()>;
+
+    /// Initialize the account to hold a worker's statistics.
+    fn init(&mut self, worker: Pubkey) -> Result<()>;
+
+    /// Whether the account has been initialized for a worker.
+    fn is_initialized(&self) -> bool;
+
+    /// Add activity to the given epoch's statistics, resetting them if a new epoch has begun.
+    fn record(&mut self, epoch: u64, activity: WorkerActivity) -> Result<()>;
+}
+
+impl WorkerStatsAccount for Account<'_, WorkerStats> {
+    fn pubkey(&self) -> Pubkey {
+        WorkerStats::pubkey(self.worker)
+    }
+
+    fn heartbeat(&mut self, slot: u64) -> Result<()> {
+        self.heartbeat_at = slot;
+        Ok(())
+    }
+
+    fn init(&mut self, worker: Pubkey) -> Result<()> {
+        self.epoch = 0;
+        self.execs = 0;
+        self.fees = 0;
+        self.heartbeat_at = 0;
+        self.kickoffs = 0;
+        self.reimbursements = 0;
+        self.worker = worker;
+        Ok(())
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.worker.ne(&Pubkey::default())
+    }
+
+    fn record(&mut self, epoch: u64, activity: WorkerActivity) -> Result<()> {
+        let current = self.activity(epoch);
+        self.epoch = epoch;
+        self.execs = current.execs.checked_add(activity.execs).unwrap();
+        self.fees = current.fees.checked_add(activity.fees).unwrap();
+        self.kickoffs = current.kickoffs.checked_add(activity.kickoffs).unwrap();
+        self.reimbursements = current
+            .reimbursements
+            .checked_add(activity.reimbursements)
+            .unwrap();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(heartbeat_at: u64) -> WorkerStats {
+        WorkerStats {
+            epoch: 0,
+            execs: 0,
+            fees: 0,
+            heartbeat_at,
+            kickoffs: 0,
+            reimbursements: 0,
+            worker: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn is_stale_after_heartbeat_timeout() {
+        let stats = stats(100);
+        assert!(!stats.is_stale(100, 50));
+        assert!(!stats.is_stale(150, 50));
+        assert!(stats.is_stale(151, 50));
+    }
+
+    #[test]
+    fn is_stale_never_with_zero_timeout() {
+        assert!(!stats(0).is_stale(u64::MAX, 0));
+    }
+
+    #[test]
+    fn is_stale_ignores_future_heartbeats() {
+        assert!(!stats(200).is_stale(100, 50));
+    }
+}
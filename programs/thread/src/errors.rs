@@ -53,4 +53,35 @@ pub enum AntegenThreadError {
     #[msg("MathOverflow")]
     MathOverflow,
 
+    /// Thrown if an instruction index is out of bounds of the thread's instruction set.
+    #[msg("The instruction index is out of bounds")]
+    InvalidInstructionIndex,
+
+    /// Thrown if a thread pays fees in tokens but the fee token accounts are missing or invalid.
+    #[msg("The fee token accounts are missing or invalid")]
+    InvalidFeeTokenAccount,
+
+    /// Thrown if the fee price feed cannot be read or reports a non-positive price.
+    #[msg("The fee price feed is invalid or stale")]
+    InvalidFeePriceFeed,
+
+    /// Thrown if a worker processes a thread assigned to another worker before the grace period has passed.
+    #[msg("The thread is assigned to another worker")]
+    WorkerNotAssigned,
+
+    /// Thrown if a thread is processed with a signatory other than the worker's.
+    #[msg("The signatory is not the worker's signatory")]
+    InvalidSignatory,
+
+    /// Thrown if a worker outside the thread's pool processes it before the timeout window has passed.
+    #[msg("The worker is not a member of the thread's pool")]
+    WorkerNotInPool,
+
+    /// Thrown if a cron trigger's schedule cannot be parsed.
+    #[msg("The cron schedule could not be parsed")]
+    InvalidSchedule,
+
+    /// Thrown if a thread is moved to a pool whose account was not provided.
+    #[msg("The thread's pool account is invalid")]
+    InvalidPool,
 }
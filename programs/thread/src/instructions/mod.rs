@@ -1,9 +1,13 @@
 pub mod get_crate_info;
+pub mod thread_check;
 pub mod thread_create;
 pub mod thread_delete;
 pub mod thread_exec;
 pub mod thread_instruction_add;
+pub mod thread_instruction_insert;
+pub mod thread_instruction_move;
 pub mod thread_instruction_remove;
+pub mod thread_instruction_replace;
 pub mod thread_kickoff;
 pub mod thread_pause;
 pub mod thread_reset;
@@ -12,11 +16,15 @@ pub mod thread_update;
 pub mod thread_withdraw;
 
 pub use get_crate_info::*;
+pub use thread_check::*;
 pub use thread_create::*;
 pub use thread_delete::*;
 pub use thread_exec::*;
 pub use thread_instruction_add::*;
+pub use thread_instruction_insert::*;
+pub use thread_instruction_move::*;
 pub use thread_instruction_remove::*;
+pub use thread_instruction_replace::*;
 pub use thread_kickoff::*;
 pub use thread_pause::*;
 pub use thread_reset::*;
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use antegen_network_program::state::{Pool, Registry};
+use antegen_utils::thread::Trigger;
+
+use crate::{errors::*, state::*};
+
+use super::thread_kickoff::{cron_reference_timestamp, evaluate_trigger, next_timestamp};
+
+/// Accounts required by the `thread_check` instruction.
+#[derive(Accounts)]
+pub struct ThreadCheck<'info> {
+    /// The worker pool serving the thread. When passed with the registry, the response names the assigned worker.
+    #[account(address = Pool::pubkey(thread.pool_id))]
+    pub pool: Option<Box<Account<'info, Pool>>>,
+
+    /// The network registry.
+    #[account(address = Registry::pubkey())]
+    pub registry: Option<Box<Account<'info, Registry>>>,
+
+    /// The thread to evaluate.
+    #[account(
+        seeds = [
+            SEED_THREAD,
+            thread.authority.as_ref(),
+            thread.id.as_slice(),
+        ],
+        bump = thread.bump,
+    )]
+    pub thread: Box<Account<'info, Thread>>,
+}
+
+pub fn handler(ctx: Context<ThreadCheck>) -> Result<ThreadCheckResponse> {
+    // Get accounts.
+    let thread = &ctx.accounts.thread;
+    let clock = Clock::get().unwrap();
+
+    // Find the threshold the trigger is waiting for.
+    let mut is_schedule_invalid = false;
+    let (next_timestamp, next_slot) = match thread.trigger.clone() {
+        Trigger::Cron { schedule, .. } => (
+            match cron_reference_timestamp(thread) {
+                Ok(reference_timestamp) => next_timestamp(reference_timestamp, schedule)
+                    .unwrap_or_else(|_| {
+                        is_schedule_invalid = true;
+                        None
+                    }),
+                Err(_) => None,
+            },
+            None,
+        ),
+        Trigger::Timestamp { unix_ts } => (Some(unix_ts), None),
+        Trigger::Slot { slot } => (None, Some(slot)),
+        Trigger::Epoch { epoch } => (
+            None,
+            Some(EpochSchedule::get()?.get_first_slot_in_epoch(epoch)),
+        ),
+        Trigger::EveryEpoch { offset_slots } => {
+            let next_epoch = match thread.exec_context.map(|e| e.trigger_context) {
+                Some(TriggerContext::Epoch { started_at }) if started_at >= clock.epoch => {
+                    started_at.checked_add(1).unwrap()
+                }
+                _ => clock.epoch,
+            };
+            (
+                None,
+                EpochSchedule::get()?
+                    .get_first_slot_in_epoch(next_epoch)
+                    .checked_add(offset_slots),
+            )
+        }
+        _ => (None, None),
+    };
+
+    // Evaluate the thread the same way thread_kickoff and thread_exec would.
+    let reason = if thread.paused {
+        Some(ThreadCheckFailure::Paused)
+    } else if is_schedule_invalid {
+        Some(ThreadCheckFailure::InvalidSchedule)
+    } else if thread.next_instruction.is_some() {
+        // The thread is mid-run, so only the rate limit applies.
+        match thread.exec_context {
+            None => Some(ThreadCheckFailure::InvalidThreadState),
+            Some(exec_context)
+                if exec_context.last_exec_at == clock.slot
+                    && exec_context.execs_since_slot >= thread.rate_limit =>
+            {
+                Some(ThreadCheckFailure::RateLimitExceeded)
+            }
+            Some(_) => None,
+        }
+    } else {
+        match evaluate_trigger(thread, ctx.remaining_accounts, &clock) {
+            Ok(_) => None,
+            Err(err) if err == AntegenThreadError::InvalidThreadState.into() => {
+                Some(ThreadCheckFailure::InvalidThreadState)
+            }
+            Err(_) => Some(ThreadCheckFailure::TriggerConditionFailed),
+        }
+    };
+
+    // Find the pool member assigned to the thread, if the pool and registry were passed.
+    let assigned_worker = match (&ctx.accounts.pool, &ctx.accounts.registry) {
+        (Some(pool), Some(registry)) => pool.assigned_worker(
+            thread.key(),
+            thread.due_slot(clock.slot),
+            registry.nonce,
+        ),
+        _ => None,
+    };
+
+    let response = ThreadCheckResponse {
+        is_due: reason.is_none(),
+        next_timestamp,
+        next_slot,
+        reason,
+        assigned_worker,
+    };
+    msg!("{:?}", response);
+
+    Ok(response)
+}
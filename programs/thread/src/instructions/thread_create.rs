@@ -72,6 +72,8 @@ pub fn handler(
     thread.created_at = Clock::get().unwrap().into();
     thread.exec_context = None;
     thread.fee = MINIMUM_FEE;
+    thread.fee_model = FeeModel::Flat;
+    thread.fee_token_account = None;
     thread.id = id_bytes;
     thread.instructions = instructions;
     thread.name = match id {
@@ -80,6 +82,7 @@ pub fn handler(
     };
     thread.next_instruction = None;
     thread.paused = false;
+    thread.pool_id = 0;
     thread.rate_limit = u64::MAX;
     thread.trigger = trigger;
 
@@ -2,16 +2,31 @@ use anchor_lang::{
     prelude::*,
     solana_program::{
         instruction::Instruction,
+        compute_units::sol_remaining_compute_units,
+        native_token::LAMPORTS_PER_SOL,
         program::{get_return_data, invoke_signed}
     },
     AnchorDeserialize, InstructionData,
 };
-use antegen_network_program::state::{Pool, Worker, WorkerAccount, WorkerCommission, SEED_WORKER_COMMISSION};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+};
+use antegen_network_program::{
+    cpi::accounts::WorkerStatsRecord,
+    program::NetworkProgram,
+    state::{
+        Config, Pool, Registry, Worker, WorkerAccount, WorkerActivity, WorkerCommission,
+        WorkerStats, SEED_WORKER_COMMISSION,
+    },
+    SEED_THREAD_STATS_AUTHORITY,
+};
 use antegen_utils::thread::{SerializableInstruction, ThreadResponse, PAYER_PUBKEY};
+use pyth_sdk_solana::state::SolanaPriceAccount;
 use crate::{errors::*, state::*, TRANSACTION_BASE_FEE_REIMBURSEMENT};
 
-/// The ID of the pool workers must be a member of to collect fees.
-const POOL_ID: u64 = 0;
+/// The maximum age of a fee price feed update, in seconds.
+const FEE_PRICE_STALENESS_THRESHOLD: u64 = 60;
 
 #[derive(Debug, Clone, Copy)]
 struct BalanceSnapshot {
@@ -51,14 +66,46 @@ pub struct ThreadExec<'info> {
     )]
     pub commission: Account<'info, WorkerCommission>,
 
-    /// The active worker pool.
-    #[account(address = Pool::pubkey(POOL_ID))]
+    /// The worker's fee token account, required if the thread pays fees in tokens.
+    #[account(mut)]
+    pub commission_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The network config, required if the thread pays fees in tokens.
+    #[account(address = Config::pubkey())]
+    pub config: Option<Box<Account<'info, Config>>>,
+
+    /// The network's fee token mint, required if the thread pays fees in tokens.
+    pub fee_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: The price feed is verified against the network config.
+    pub fee_price_feed: Option<UncheckedAccount<'info>>,
+
+    /// The network program, which records the worker's activity.
+    pub network_program: Program<'info, NetworkProgram>,
+
+    /// The worker pool serving the thread. Only its members collect fees.
+    #[account(address = Pool::pubkey(thread.pool_id))]
     pub pool: Box<Account<'info, Pool>>,
 
+    /// The network registry.
+    #[account(address = Registry::pubkey())]
+    pub registry: Box<Account<'info, Registry>>,
+
     /// The signatory.
     #[account(mut)]
     pub signatory: Signer<'info>,
 
+    /// CHECK: The worker's statistics are initialized and updated by the network program.
+    #[account(mut, address = WorkerStats::pubkey(worker.key()))]
+    pub stats: UncheckedAccount<'info>,
+
+    /// CHECK: The thread program's stats authority, which signs the activity reports.
+    #[account(seeds = [SEED_THREAD_STATS_AUTHORITY], bump)]
+    pub stats_authority: UncheckedAccount<'info>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+
     /// The thread to execute.
     #[account(
         mut,
@@ -74,11 +121,161 @@ pub struct ThreadExec<'info> {
     )]
     pub thread: Box<Account<'info, Thread>>,
 
+    /// The thread's fee token account, required if the thread pays fees in tokens.
+    #[account(mut)]
+    pub thread_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SPL token program, required if the thread pays fees in tokens.
+    pub token_program: Option<Program<'info, Token>>,
+
     /// The worker.
     #[account(address = worker.pubkey())]
     pub worker: Account<'info, Worker>,
 }
 
+impl<'info> ThreadExec<'info> {
+    /// Pay the worker's fee from the thread's token account, converting the lamport fee at the network's fee price.
+    fn transfer_fee_tokens(&self, fee_token_account: Pubkey, lamports: u64) -> Result<()> {
+        let (
+            Some(commission_token_account),
+            Some(config),
+            Some(fee_mint),
+            Some(fee_price_feed),
+            Some(thread_token_account),
+            Some(token_program),
+        ) = (
+            &self.commission_token_account,
+            &self.config,
+            &self.fee_mint,
+            &self.fee_price_feed,
+            &self.thread_token_account,
+            &self.token_program,
+        )
+        else {
+            return Err(AntegenThreadError::InvalidFeeTokenAccount.into());
+        };
+
+        // Verify the accounts match the network's fee token configuration.
+        require!(
+            config.fee_mint.eq(&Some(fee_mint.key()))
+                && config.fee_price_feed.eq(&Some(fee_price_feed.key())),
+            AntegenThreadError::InvalidFeeTokenAccount
+        );
+        require!(
+            thread_token_account.key().eq(&fee_token_account)
+                && thread_token_account.owner.eq(&self.thread.key())
+                && thread_token_account.mint.eq(&fee_mint.key()),
+            AntegenThreadError::InvalidFeeTokenAccount
+        );
+        require!(
+            commission_token_account
+                .key()
+                .eq(&get_associated_token_address(&self.commission.key(), &fee_mint.key())),
+            AntegenThreadError::InvalidFeeTokenAccount
+        );
+
+        // Convert the lamport fee into tokens.
+        let clock = Clock::get()?;
+        let price = SolanaPriceAccount::account_info_to_feed(&fee_price_feed.to_account_info())
+            .map_err(|_| AntegenThreadError::InvalidFeePriceFeed)?
+            .get_price_no_older_than(clock.unix_timestamp, FEE_PRICE_STALENESS_THRESHOLD)
+            .ok_or(AntegenThreadError::InvalidFeePriceFeed)?;
+        let amount = lamports_to_tokens(lamports, price.price, price.expo, fee_mint.decimals)?;
+
+        // Transfer the tokens to the worker.
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: thread_token_account.to_account_info(),
+                    mint: fee_mint.to_account_info(),
+                    to: commission_token_account.to_account_info(),
+                    authority: self.thread.to_account_info(),
+                },
+                &[&[
+                    SEED_THREAD,
+                    self.thread.authority.as_ref(),
+                    self.thread.id.as_slice(),
+                    &[self.thread.bump],
+                ]],
+            ),
+            amount,
+            fee_mint.decimals,
+        )
+    }
+}
+
+/// Convert a lamport amount into token base units, given the price of one SOL in the token.
+fn lamports_to_tokens(lamports: u64, price: i64, expo: i32, decimals: u8) -> Result<u64> {
+    require!(price.gt(&0), AntegenThreadError::InvalidFeePriceFeed);
+    let amount = (|| {
+        let numerator = (lamports as u128)
+            .checked_mul(price as u128)?
+            .checked_mul(10u128.checked_pow(decimals as u32)?)?;
+        let denominator = LAMPORTS_PER_SOL as u128;
+        if expo.lt(&0) {
+            numerator.checked_div(denominator.checked_mul(10u128.checked_pow(expo.unsigned_abs())?)?)
+        } else {
+            numerator
+                .checked_mul(10u128.checked_pow(expo as u32)?)?
+                .checked_div(denominator)
+        }
+    })()
+    .and_then(|amount| u64::try_from(amount).ok())
+    .ok_or(AntegenThreadError::MathOverflow)?;
+    Ok(amount)
+}
+
+/// Verify the worker may process the thread at the given slot.
+/// Pool members follow the pool's assignment rule, while workers outside the pool must wait out the timeout window
+/// measured from `timeout_due_slot`. If the moment the thread became due is unknown, only pool members may process it.
+pub(crate) fn verify_assignment(
+    pool: &Pool,
+    registry: &Registry,
+    signatory: &Signer,
+    thread: &Account<Thread>,
+    worker: &Account<Worker>,
+    timeout_due_slot: Option<u64>,
+    slot: u64,
+) -> Result<()> {
+    // Only the worker's own signatory can claim the worker's assignments.
+    let claimant = if worker.signatory.eq(&signatory.key()) {
+        worker.key()
+    } else {
+        signatory.key()
+    };
+    if pool.workers.contains(&claimant) {
+        require!(
+            pool.is_assigned(claimant, thread.key(), thread.due_slot(slot), registry.nonce, slot),
+            AntegenThreadError::WorkerNotAssigned
+        );
+    } else {
+        let is_admitted = match timeout_due_slot {
+            Some(timeout_due_slot) => pool.admits(claimant, timeout_due_slot, slot),
+            None => pool.workers.is_empty(),
+        };
+        require!(is_admitted, AntegenThreadError::WorkerNotInPool);
+    }
+    Ok(())
+}
+
+/// Report the work a worker performed for a thread to the network program.
+pub(crate) fn record_worker_activity<'info>(
+    network_program: &Program<'info, NetworkProgram>,
+    accounts: WorkerStatsRecord<'info>,
+    stats_authority_bump: u8,
+    activity: WorkerActivity,
+) -> Result<()> {
+    antegen_network_program::cpi::worker_stats_record(
+        CpiContext::new_with_signer(
+            network_program.to_account_info(),
+            accounts,
+            &[&[SEED_THREAD_STATS_AUTHORITY, &[stats_authority_bump]]],
+        ),
+        activity,
+    )
+}
+
 fn transfer_lamports<'info>(
     from: &AccountInfo<'info>,
     to: &AccountInfo<'info>,
@@ -105,6 +302,18 @@ pub fn handler(ctx: Context<ThreadExec>) -> Result<()> {
     let thread = &mut ctx.accounts.thread;
     let worker = &ctx.accounts.worker;
 
+    // Verify the thread is assigned to this worker, or its assigned worker's grace period has passed.
+    // Workers outside the pool may only execute the thread once it has been due for longer than the timeout window.
+    verify_assignment(
+        pool,
+        &ctx.accounts.registry,
+        signatory,
+        thread,
+        worker,
+        Some(thread.due_slot(clock.slot)),
+        clock.slot,
+    )?;
+
     // If the rate limit has been met, exit early.
     if thread.exec_context.unwrap().last_exec_at == clock.slot
         && thread.exec_context.unwrap().execs_since_slot >= thread.rate_limit
@@ -127,7 +336,8 @@ pub fn handler(ctx: Context<ThreadExec>) -> Result<()> {
     }
 
     let is_delete = instruction.data[..8] == *crate::instruction::ThreadDelete::DISCRIMINATOR;
-    // Invoke the provided instruction.
+    // Invoke the provided instruction, measuring the compute units it consumes.
+    let initial_compute_units = sol_remaining_compute_units();
     invoke_signed(
         &Instruction::from(&*instruction),
         ctx.remaining_accounts,
@@ -138,6 +348,7 @@ pub fn handler(ctx: Context<ThreadExec>) -> Result<()> {
             &[thread.bump],
         ]],
     )?;
+    let compute_units = initial_compute_units.saturating_sub(sol_remaining_compute_units());
 
     if is_delete {
         thread.next_instruction = None;
@@ -261,13 +472,53 @@ pub fn handler(ctx: Context<ThreadExec>) -> Result<()> {
     }
 
     // Only process worker fees if they haven't already been processed by inner instruction
+    let mut fees = 0;
     if pool.clone().into_inner().workers.contains(&worker.key())
         && balance_changes.commission.eq(&0)
     {
-        transfer_lamports(
-            &thread.to_account_info(),
-            &commission.to_account_info(),
-            thread.fee,
+        let fee = thread
+            .fee_model
+            .fee(thread.fee, compute_units)
+            .ok_or(AntegenThreadError::MathOverflow)?;
+        // Threads left holding a fee token account once the network stops accepting a fee token pay in lamports.
+        let fee_token_account = thread.fee_token_account.filter(|_| {
+            ctx.accounts
+                .config
+                .as_ref()
+                .map(|config| config.fee_mint.is_some())
+                .unwrap_or(true)
+        });
+        match fee_token_account {
+            None => transfer_lamports(
+                &thread.to_account_info(),
+                &commission.to_account_info(),
+                fee,
+            )?,
+            Some(fee_token_account) => ctx.accounts.transfer_fee_tokens(fee_token_account, fee)?,
+        }
+        fees = fee;
+    }
+
+    // Record the exec against the worker, if it was signed by the worker's signatory.
+    let accounts = &ctx.accounts;
+    if accounts.worker.signatory.eq(&accounts.signatory.key()) {
+        record_worker_activity(
+            &accounts.network_program,
+            WorkerStatsRecord {
+                registry: accounts.registry.to_account_info(),
+                signatory: accounts.signatory.to_account_info(),
+                stats: accounts.stats.to_account_info(),
+                stats_authority: accounts.stats_authority.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                worker: accounts.worker.to_account_info(),
+            },
+            ctx.bumps.stats_authority,
+            WorkerActivity {
+                execs: 1,
+                fees,
+                kickoffs: 0,
+                reimbursements: required_reimbursement,
+            },
         )?;
     }
 
@@ -0,0 +1,43 @@
+use {
+    crate::{errors::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+/// Accounts required by the `thread_instruction_move` instruction.
+#[derive(Accounts)]
+#[instruction(from: u64, to: u64)]
+pub struct ThreadInstructionMove<'info> {
+    /// The authority (owner) of the thread.
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The thread to be edited.
+    #[account(
+        mut,
+        seeds = [
+            SEED_THREAD,
+            thread.authority.as_ref(),
+            thread.id.as_slice(),
+        ],
+        bump = thread.bump,
+        has_one = authority,
+        constraint = thread.next_instruction.is_none() @ AntegenThreadError::ThreadBusy,
+    )]
+    pub thread: Account<'info, Thread>,
+}
+
+pub fn handler(ctx: Context<ThreadInstructionMove>, from: u64, to: u64) -> Result<()> {
+    // Get accounts
+    let thread = &mut ctx.accounts.thread;
+
+    // Move the instruction from one index to the other, shifting the instructions in between.
+    let len = thread.instructions.len();
+    require!(
+        (from as usize).lt(&len) && (to as usize).lt(&len),
+        AntegenThreadError::InvalidInstructionIndex
+    );
+    let instruction = thread.instructions.remove(from as usize);
+    thread.instructions.insert(to as usize, instruction);
+
+    Ok(())
+}
@@ -4,9 +4,14 @@ use std::{
     str::FromStr,
 };
 
-use crate::{errors::*, state::*, TRANSACTION_BASE_FEE_REIMBURSEMENT};
-use anchor_lang::prelude::*;
-use antegen_network_program::state::{Worker, WorkerAccount};
+use crate::{errors::*, instructions::thread_exec::{record_worker_activity, verify_assignment}, state::*, TRANSACTION_BASE_FEE_REIMBURSEMENT};
+use anchor_lang::{prelude::*, solana_program::clock::DEFAULT_MS_PER_SLOT};
+use antegen_network_program::{
+    cpi::accounts::WorkerStatsRecord,
+    program::NetworkProgram,
+    state::{Pool, Registry, Worker, WorkerAccount, WorkerActivity, WorkerStats},
+    SEED_THREAD_STATS_AUTHORITY,
+};
 use antegen_utils::thread::Trigger;
 use chrono::{DateTime, Utc};
 use pyth_sdk_solana::state::SolanaPriceAccount;
@@ -15,10 +20,32 @@ use solana_cron::Schedule;
 /// Accounts required by the `thread_kickoff` instruction.
 #[derive(Accounts)]
 pub struct ThreadKickoff<'info> {
+    /// The network program, which records the worker's activity.
+    pub network_program: Program<'info, NetworkProgram>,
+
+    /// The worker pool serving the thread.
+    #[account(address = Pool::pubkey(thread.pool_id))]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The network registry.
+    #[account(address = Registry::pubkey())]
+    pub registry: Box<Account<'info, Registry>>,
+
     /// The signatory.
     #[account(mut)]
     pub signatory: Signer<'info>,
 
+    /// CHECK: The worker's statistics are initialized and updated by the network program.
+    #[account(mut, address = WorkerStats::pubkey(worker.key()))]
+    pub stats: UncheckedAccount<'info>,
+
+    /// CHECK: The thread program's stats authority, which signs the activity reports.
+    #[account(seeds = [SEED_THREAD_STATS_AUTHORITY], bump)]
+    pub stats_authority: UncheckedAccount<'info>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+
     /// The thread to kickoff.
     #[account(
         mut,
@@ -34,7 +61,10 @@ pub struct ThreadKickoff<'info> {
     pub thread: Box<Account<'info, Thread>>,
 
     /// The worker.
-    #[account(address = worker.pubkey())]
+    #[account(
+        address = worker.pubkey(),
+        constraint = worker.signatory.eq(&signatory.key()) @ AntegenThreadError::InvalidSignatory,
+    )]
     pub worker: Account<'info, Worker>,
 }
 
@@ -44,78 +74,167 @@ pub fn handler(ctx: Context<ThreadKickoff>) -> Result<()> {
     let thread = &mut ctx.accounts.thread;
     let clock = Clock::get().unwrap();
 
-    match thread.trigger.clone() {
+    // Verify the thread is assigned to this worker, or its assigned worker's grace period has passed.
+    // Workers outside the pool may only kick off the thread once it has been due for longer than the timeout window,
+    // and never for triggers whose due moment is unknown.
+    verify_assignment(
+        &ctx.accounts.pool,
+        &ctx.accounts.registry,
+        signatory,
+        thread,
+        &ctx.accounts.worker,
+        kickoff_due_slot(thread, &clock)?,
+        clock.slot,
+    )?;
+
+    // Verify the trigger condition is active and set the new exec context.
+    thread.exec_context = Some(evaluate_trigger(thread, ctx.remaining_accounts, &clock)?);
+
+    // If we make it here, the trigger is active. Update the next instruction and be done.
+    if let Some(kickoff_instruction) = thread.instructions.first() {
+        thread.next_instruction = Some(kickoff_instruction.clone());
+    }
+
+    // Now that the account is sufficiently funded, reallocate
+    thread.realloc()?;
+
+    // Reimburse signatory for transaction fee.
+    **thread.to_account_info().try_borrow_mut_lamports()? = thread
+        .to_account_info()
+        .lamports()
+        .checked_sub(TRANSACTION_BASE_FEE_REIMBURSEMENT)
+        .unwrap();
+    **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
+        .to_account_info()
+        .lamports()
+        .checked_add(TRANSACTION_BASE_FEE_REIMBURSEMENT)
+        .unwrap();
+
+    // Record the kickoff against the worker.
+    let accounts = &ctx.accounts;
+    record_worker_activity(
+        &accounts.network_program,
+        WorkerStatsRecord {
+            registry: accounts.registry.to_account_info(),
+            signatory: accounts.signatory.to_account_info(),
+            stats: accounts.stats.to_account_info(),
+            stats_authority: accounts.stats_authority.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            worker: accounts.worker.to_account_info(),
+        },
+        ctx.bumps.stats_authority,
+        WorkerActivity {
+            execs: 0,
+            fees: 0,
+            kickoffs: 1,
+            reimbursements: TRANSACTION_BASE_FEE_REIMBURSEMENT,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Estimates the slot a thread's kickoff became due at, for the pool's timeout window.
+/// Slot, cron, timestamp, epoch and now triggers fix the moment, bounded below by the thread's last exec or its creation.
+/// Account and Pyth triggers fire on events the program cannot date, so they return `None` and are left to pool members.
+fn kickoff_due_slot(thread: &Thread, clock: &Clock) -> Result<Option<u64>> {
+    let idle_since = thread
+        .exec_context
+        .map_or(thread.created_at.slot, |exec_context| exec_context.last_exec_at);
+    let due_timestamp = match thread.trigger.clone() {
+        Trigger::Account { .. } | Trigger::Pyth { .. } => return Ok(None),
+        Trigger::Now => return Ok(Some(idle_since)),
+        Trigger::Slot { slot } => return Ok(Some(slot.max(idle_since))),
+        Trigger::Epoch { epoch } => {
+            let first_slot = EpochSchedule::get()?.get_first_slot_in_epoch(epoch);
+            return Ok(Some(first_slot.max(idle_since)));
+        }
+        Trigger::EveryEpoch { offset_slots } => {
+            let threshold_slot = EpochSchedule::get()?
+                .get_first_slot_in_epoch(clock.epoch)
+                .saturating_add(offset_slots);
+            return Ok(Some(threshold_slot.max(idle_since)));
+        }
+        Trigger::Cron { schedule, .. } => next_timestamp(cron_reference_timestamp(thread)?, schedule)?,
+        Trigger::Timestamp { unix_ts } => Some(unix_ts),
+    };
+
+    // Convert the time elapsed since the due moment into slots.
+    Ok(Some(match due_timestamp {
+        Some(due_timestamp) => {
+            let elapsed_ms = (clock.unix_timestamp.saturating_sub(due_timestamp).max(0) as u64)
+                .saturating_mul(1_000);
+            clock
+                .slot
+                .saturating_sub(elapsed_ms / DEFAULT_MS_PER_SLOT)
+                .max(idle_since)
+        }
+        None => idle_since,
+    }))
+}
+
+/// Verifies the thread's trigger condition is active and returns the exec context to kickoff with.
+pub(crate) fn evaluate_trigger(
+    thread: &Thread,
+    remaining_accounts: &[AccountInfo],
+    clock: &Clock,
+) -> Result<ExecContext> {
+    let trigger_context = match thread.trigger.clone() {
         Trigger::Account {
             address,
             offset,
             size,
         } => {
             // Verify proof that account data has been updated.
-            match ctx.remaining_accounts.first() {
-                None => {
-                    return Err(AntegenThreadError::TriggerConditionFailed.into());
-                }
-                Some(account_info) => {
-                    // Verify the remaining account is the account this thread is listening for.
-                    require!(
-                        address.eq(account_info.key),
-                        AntegenThreadError::TriggerConditionFailed
-                    );
-
-                    // Begin computing the data hash of this account.
-                    let mut hasher = DefaultHasher::new();
-                    let data = &account_info.try_borrow_data().unwrap();
-                    let offset = offset as usize;
-                    let range_end = offset.checked_add(size as usize).unwrap() as usize;
-                    if data.len().gt(&range_end) {
-                        data[offset..range_end].hash(&mut hasher);
-                    } else {
-                        data[offset..].hash(&mut hasher)
-                    }
-                    let data_hash = hasher.finish();
-
-                    // Verify the data hash is different than the prior data hash.
-                    if let Some(exec_context) = thread.exec_context {
-                        match exec_context.trigger_context {
-                            TriggerContext::Account {
-                                data_hash: prior_data_hash,
-                            } => {
-                                require!(
-                                    data_hash.ne(&prior_data_hash),
-                                    AntegenThreadError::TriggerConditionFailed
-                                )
-                            }
-                            _ => return Err(AntegenThreadError::InvalidThreadState.into()),
-                        }
-                    }
+            let account_info = remaining_accounts
+                .first()
+                .ok_or(AntegenThreadError::TriggerConditionFailed)?;
+
+            // Verify the remaining account is the account this thread is listening for.
+            require!(
+                address.eq(account_info.key),
+                AntegenThreadError::TriggerConditionFailed
+            );
 
-                    // Set a new exec context with the new data hash and slot number.
-                    thread.exec_context = Some(ExecContext {
-                        exec_index: 0,
-                        execs_since_reimbursement: 0,
-                        execs_since_slot: 0,
-                        last_exec_at: clock.slot,
-                        last_exec_timestamp: clock.unix_timestamp,
-                        trigger_context: TriggerContext::Account { data_hash },
-                    })
+            // Begin computing the data hash of this account.
+            let mut hasher = DefaultHasher::new();
+            let data = &account_info.try_borrow_data().unwrap();
+            let offset = offset as usize;
+            let range_end = offset.checked_add(size as usize).unwrap() as usize;
+            if data.len().gt(&range_end) {
+                data[offset..range_end].hash(&mut hasher);
+            } else {
+                data[offset..].hash(&mut hasher)
+            }
+            let data_hash = hasher.finish();
+
+            // Verify the data hash is different than the prior data hash.
+            if let Some(exec_context) = thread.exec_context {
+                match exec_context.trigger_context {
+                    TriggerContext::Account {
+                        data_hash: prior_data_hash,
+                    } => {
+                        require!(
+                            data_hash.ne(&prior_data_hash),
+                            AntegenThreadError::TriggerConditionFailed
+                        )
+                    }
+                    _ => return Err(AntegenThreadError::InvalidThreadState.into()),
                 }
             }
+
+            TriggerContext::Account { data_hash }
         }
         Trigger::Cron {
             schedule,
             skippable,
         } => {
             // Get the reference timestamp for calculating the thread's scheduled target timestamp.
-            let reference_timestamp = match thread.exec_context.clone() {
-                None => thread.created_at.unix_timestamp,
-                Some(exec_context) => match exec_context.trigger_context {
-                    TriggerContext::Cron { started_at } => started_at,
-                    _ => return Err(AntegenThreadError::InvalidThreadState.into()),
-                },
-            };
+            let reference_timestamp = cron_reference_timestamp(thread)?;
             msg!("reference_timestamp: {}", reference_timestamp);
+
             // Verify the current timestamp is greater than or equal to the threshold timestamp.
-            let threshold_timestamp = next_timestamp(reference_timestamp, schedule.clone())
+            let threshold_timestamp = next_timestamp(reference_timestamp, schedule)?
                 .ok_or(AntegenThreadError::TriggerConditionFailed)?;
             require!(
                 clock.unix_timestamp.ge(&threshold_timestamp),
@@ -130,8 +249,8 @@ pub fn handler(ctx: Context<ThreadKickoff>) -> Result<()> {
                 threshold_timestamp
             };
 
-            // Set the exec context.
-            thread.exec_context = Some(ExecContext {
+            // Cron threads record the reference timestamp rather than the current one.
+            return Ok(ExecContext {
                 exec_index: 0,
                 execs_since_reimbursement: 0,
                 execs_since_slot: 0,
@@ -141,63 +260,61 @@ pub fn handler(ctx: Context<ThreadKickoff>) -> Result<()> {
             });
         }
         Trigger::Now => {
-            // Set the exec context.
             require!(
                 thread.exec_context.is_none(),
                 AntegenThreadError::InvalidThreadState
             );
-            thread.exec_context = Some(ExecContext {
-                exec_index: 0,
-                execs_since_reimbursement: 0,
-                execs_since_slot: 0,
-                last_exec_at: clock.slot,
-                last_exec_timestamp: clock.unix_timestamp,
-                trigger_context: TriggerContext::Now,
-            });
+            TriggerContext::Now
         }
         Trigger::Slot { slot } => {
             require!(
                 clock.slot.ge(&slot),
                 AntegenThreadError::TriggerConditionFailed
             );
-            thread.exec_context = Some(ExecContext {
-                exec_index: 0,
-                execs_since_reimbursement: 0,
-                execs_since_slot: 0,
-                last_exec_at: clock.slot,
-                last_exec_timestamp: clock.unix_timestamp,
-                trigger_context: TriggerContext::Slot { started_at: slot },
-            });
+            TriggerContext::Slot { started_at: slot }
         }
         Trigger::Epoch { epoch } => {
             require!(
                 clock.epoch.ge(&epoch),
                 AntegenThreadError::TriggerConditionFailed
             );
-            thread.exec_context = Some(ExecContext {
-                exec_index: 0,
-                execs_since_reimbursement: 0,
-                execs_since_slot: 0,
-                last_exec_at: clock.slot,
-                last_exec_timestamp: clock.unix_timestamp,
-                trigger_context: TriggerContext::Epoch { started_at: epoch },
-            })
+            TriggerContext::Epoch { started_at: epoch }
+        }
+        Trigger::EveryEpoch { offset_slots } => {
+            // Verify the thread has not already been kicked off in the current epoch.
+            if let Some(exec_context) = thread.exec_context {
+                match exec_context.trigger_context {
+                    TriggerContext::Epoch { started_at } => {
+                        require!(
+                            clock.epoch.gt(&started_at),
+                            AntegenThreadError::TriggerConditionFailed
+                        )
+                    }
+                    _ => return Err(AntegenThreadError::InvalidThreadState.into()),
+                }
+            }
+
+            // Verify the current slot is far enough into the epoch. An offset past the last slot is never reached.
+            let threshold_slot = EpochSchedule::get()?
+                .get_first_slot_in_epoch(clock.epoch)
+                .checked_add(offset_slots)
+                .ok_or(AntegenThreadError::TriggerConditionFailed)?;
+            require!(
+                clock.slot.ge(&threshold_slot),
+                AntegenThreadError::TriggerConditionFailed
+            );
+            TriggerContext::Epoch {
+                started_at: clock.epoch,
+            }
         }
         Trigger::Timestamp { unix_ts } => {
             require!(
                 clock.unix_timestamp.ge(&unix_ts),
                 AntegenThreadError::TriggerConditionFailed
             );
-            thread.exec_context = Some(ExecContext {
-                exec_index: 0,
-                execs_since_reimbursement: 0,
-                execs_since_slot: 0,
-                last_exec_at: clock.slot,
-                last_exec_timestamp: clock.unix_timestamp,
-                trigger_context: TriggerContext::Timestamp {
-                    started_at: unix_ts,
-                },
-            })
+            TriggerContext::Timestamp {
+                started_at: unix_ts,
+            }
         }
         Trigger::Pyth {
             price_feed: price_feed_pubkey,
@@ -205,88 +322,255 @@ pub fn handler(ctx: Context<ThreadKickoff>) -> Result<()> {
             limit,
         } => {
             // Verify price limit has been reached.
-            match ctx.remaining_accounts.first() {
-                None => {
-                    return Err(AntegenThreadError::TriggerConditionFailed.into());
-                }
-                Some(account_info) => {
-                    require!(
-                        price_feed_pubkey.eq(account_info.key),
-                        AntegenThreadError::TriggerConditionFailed
-                    );
-                    const STALENESS_THRESHOLD: u64 = 60; // staleness threshold in seconds
-                    let price_feed =
-                        SolanaPriceAccount::account_info_to_feed(account_info).unwrap();
-                    let current_timestamp = Clock::get()?.unix_timestamp;
-                    let current_price = price_feed
-                        .get_price_no_older_than(current_timestamp, STALENESS_THRESHOLD)
-                        .unwrap();
-                    match equality {
-                        Equality::GreaterThanOrEqual => {
-                            require!(
-                                current_price.price.ge(&limit),
-                                AntegenThreadError::TriggerConditionFailed
-                            );
-                            thread.exec_context = Some(ExecContext {
-                                exec_index: 0,
-                                execs_since_reimbursement: 0,
-                                execs_since_slot: 0,
-                                last_exec_at: clock.slot,
-                                last_exec_timestamp: clock.unix_timestamp,
-                                trigger_context: TriggerContext::Pyth {
-                                    price: current_price.price,
-                                },
-                            });
-                        }
-                        Equality::LessThanOrEqual => {
-                            require!(
-                                current_price.price.le(&limit),
-                                AntegenThreadError::TriggerConditionFailed
-                            );
-                            thread.exec_context = Some(ExecContext {
-                                exec_index: 0,
-                                execs_since_reimbursement: 0,
-                                execs_since_slot: 0,
-                                last_exec_at: clock.slot,
-                                last_exec_timestamp: clock.unix_timestamp,
-                                trigger_context: TriggerContext::Pyth {
-                                    price: current_price.price,
-                                },
-                            });
-                        }
-                    }
-                }
+            let account_info = remaining_accounts
+                .first()
+                .ok_or(AntegenThreadError::TriggerConditionFailed)?;
+            require!(
+                price_feed_pubkey.eq(account_info.key),
+                AntegenThreadError::TriggerConditionFailed
+            );
+            const STALENESS_THRESHOLD: u64 = 60; // staleness threshold in seconds
+            let price_feed = SolanaPriceAccount::account_info_to_feed(account_info)
+                .map_err(|_| AntegenThreadError::TriggerConditionFailed)?;
+            let current_price = price_feed
+                .get_price_no_older_than(clock.unix_timestamp, STALENESS_THRESHOLD)
+                .ok_or(AntegenThreadError::TriggerConditionFailed)?;
+            match equality {
+                Equality::GreaterThanOrEqual => require!(
+                    current_price.price.ge(&limit),
+                    AntegenThreadError::TriggerConditionFailed
+                ),
+                Equality::LessThanOrEqual => require!(
+                    current_price.price.le(&limit),
+                    AntegenThreadError::TriggerConditionFailed
+                ),
+            }
+            TriggerContext::Pyth {
+                price: current_price.price,
             }
         }
+    };
+
+    Ok(ExecContext {
+        exec_index: 0,
+        execs_since_reimbursement: 0,
+        execs_since_slot: 0,
+        last_exec_at: clock.slot,
+        last_exec_timestamp: clock.unix_timestamp,
+        trigger_context,
+    })
+}
+
+/// Returns the timestamp a cron thread's schedule is measured from.
+pub(crate) fn cron_reference_timestamp(thread: &Thread) -> Result<i64> {
+    match thread.exec_context {
+        None => Ok(thread.created_at.unix_timestamp),
+        Some(exec_context) => match exec_context.trigger_context {
+            TriggerContext::Cron { started_at } => Ok(started_at),
+            _ => Err(AntegenThreadError::InvalidThreadState.into()),
+        },
     }
+}
 
-    // If we make it here, the trigger is active. Update the next instruction and be done.
-    if let Some(kickoff_instruction) = thread.instructions.first() {
-        thread.next_instruction = Some(kickoff_instruction.clone());
+pub(crate) fn next_timestamp(after: i64, schedule: String) -> Result<Option<i64>> {
+    Ok(Schedule::from_str(&schedule)
+        .map_err(|_err| AntegenThreadError::InvalidSchedule)?
+        .next_after(&DateTime::<Utc>::from_timestamp(after, 0).unwrap())
+        .take()
+        .map(|datetime| datetime.timestamp()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        anchor_lang::solana_program::{
+            entrypoint::SUCCESS,
+            program_stubs::{set_syscall_stubs, SyscallStubs},
+        },
+        antegen_utils::thread::ClockData,
+    };
+
+    const SLOTS_PER_EPOCH: u64 = 1_000;
+
+    /// Serves the epoch schedule sysvar read by epoch triggers.
+    struct EpochScheduleStubs;
+
+    impl SyscallStubs for EpochScheduleStubs {
+        fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let epoch_schedule = EpochSchedule::custom(SLOTS_PER_EPOCH, SLOTS_PER_EPOCH, false);
+            unsafe { *(var_addr as *mut EpochSchedule) = epoch_schedule };
+            SUCCESS
+        }
     }
 
-    // Now that the account is sufficiently funded, reallocate
-    thread.realloc()?;
+    fn thread(trigger: Trigger) -> Thread {
+        set_syscall_stubs(Box::new(EpochScheduleStubs));
+        Thread {
+            authority: Pubkey::default(),
+            bump: 0,
+            created_at: ClockData {
+                slot: 100,
+                epoch: 0,
+                unix_timestamp: 0,
+            },
+            exec_context: None,
+            fee: 0,
+            id: vec![],
+            instructions: vec![],
+            name: String::new(),
+            next_instruction: None,
+            paused: false,
+            rate_limit: 0,
+            trigger,
+            fee_token_account: None,
+            fee_model: FeeModel::Flat,
+            pool_id: 0,
+        }
+    }
 
-    // Reimburse signatory for transaction fee.
-    **thread.to_account_info().try_borrow_mut_lamports()? = thread
-        .to_account_info()
-        .lamports()
-        .checked_sub(TRANSACTION_BASE_FEE_REIMBURSEMENT)
-        .unwrap();
-    **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
-        .to_account_info()
-        .lamports()
-        .checked_add(TRANSACTION_BASE_FEE_REIMBURSEMENT)
-        .unwrap();
+    fn clock(slot: u64, unix_timestamp: i64) -> Clock {
+        Clock {
+            slot,
+            epoch: slot / SLOTS_PER_EPOCH,
+            unix_timestamp,
+            ..Clock::default()
+        }
+    }
 
-    Ok(())
-}
+    #[test]
+    fn evaluate_slot_trigger() {
+        let thread = thread(Trigger::Slot { slot: 500 });
+        assert!(evaluate_trigger(&thread, &[], &clock(499, 0)).is_err());
+        let exec_context = evaluate_trigger(&thread, &[], &clock(510, 7)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Slot { started_at: 500 });
+        assert_eq!((exec_context.last_exec_at, exec_context.last_exec_timestamp), (510, 7));
+    }
 
-fn next_timestamp(after: i64, schedule: String) -> Option<i64> {
-    Schedule::from_str(&schedule)
-        .unwrap()
-        .next_after(&DateTime::<Utc>::from_timestamp(after, 0).unwrap())
-        .take()
-        .map(|datetime| datetime.timestamp())
+    #[test]
+    fn evaluate_now_trigger_once() {
+        let mut thread = thread(Trigger::Now);
+        let exec_context = evaluate_trigger(&thread, &[], &clock(100, 0)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Now);
+        thread.exec_context = Some(exec_context);
+        assert!(evaluate_trigger(&thread, &[], &clock(101, 0)).is_err());
+    }
+
+    #[test]
+    fn evaluate_cron_trigger() {
+        let mut thread = thread(Trigger::Cron {
+            schedule: "*/10 * * * * * *".into(),
+            skippable: false,
+        });
+        assert!(evaluate_trigger(&thread, &[], &clock(100, 9)).is_err());
+
+        // Schedules which may not be skipped step through each moment, measured from the previous one.
+        let exec_context = evaluate_trigger(&thread, &[], &clock(200, 25)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Cron { started_at: 10 });
+        assert_eq!(exec_context.last_exec_timestamp, 0);
+        thread.exec_context = Some(exec_context);
+        let exec_context = evaluate_trigger(&thread, &[], &clock(200, 25)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Cron { started_at: 20 });
+
+        // Skippable schedules jump to the current moment.
+        thread.trigger = Trigger::Cron {
+            schedule: "*/10 * * * * * *".into(),
+            skippable: true,
+        };
+        let exec_context = evaluate_trigger(&thread, &[], &clock(200, 25)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Cron { started_at: 25 });
+    }
+
+    #[test]
+    fn evaluate_invalid_cron_schedule() {
+        let thread = thread(Trigger::Cron {
+            schedule: "not a schedule".into(),
+            skippable: true,
+        });
+        assert_eq!(
+            evaluate_trigger(&thread, &[], &clock(100, 0)).unwrap_err(),
+            AntegenThreadError::InvalidSchedule.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_every_epoch_trigger() {
+        let mut thread = thread(Trigger::EveryEpoch { offset_slots: 10 });
+        assert!(evaluate_trigger(&thread, &[], &clock(2_009, 0)).is_err());
+        let exec_context = evaluate_trigger(&thread, &[], &clock(2_010, 0)).unwrap();
+        assert_eq!(exec_context.trigger_context, TriggerContext::Epoch { started_at: 2 });
+
+        // The thread runs once per epoch.
+        thread.exec_context = Some(exec_context);
+        assert!(evaluate_trigger(&thread, &[], &clock(2_500, 0)).is_err());
+        assert!(evaluate_trigger(&thread, &[], &clock(3_009, 0)).is_err());
+        assert!(evaluate_trigger(&thread, &[], &clock(3_010, 0)).is_ok());
+    }
+
+    #[test]
+    fn evaluate_every_epoch_trigger_past_the_last_slot() {
+        let thread = thread(Trigger::EveryEpoch { offset_slots: u64::MAX });
+        assert_eq!(
+            evaluate_trigger(&thread, &[], &clock(2_010, 0)).unwrap_err(),
+            AntegenThreadError::TriggerConditionFailed.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_account_trigger_requires_the_account() {
+        let thread = thread(Trigger::Account {
+            address: Pubkey::new_unique(),
+            offset: 0,
+            size: 8,
+        });
+        assert!(evaluate_trigger(&thread, &[], &clock(100, 0)).is_err());
+    }
+
+    #[test]
+    fn kickoff_due_slot_of_event_triggers() {
+        let account_trigger = Trigger::Account {
+            address: Pubkey::new_unique(),
+            offset: 0,
+            size: 8,
+        };
+        let pyth_trigger = Trigger::Pyth {
+            price_feed: Pubkey::new_unique(),
+            equality: Equality::GreaterThanOrEqual,
+            limit: 0,
+        };
+        for trigger in [account_trigger, pyth_trigger] {
+            assert_eq!(kickoff_due_slot(&thread(trigger), &clock(500, 0)).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn kickoff_due_slot_of_slot_triggers() {
+        assert_eq!(kickoff_due_slot(&thread(Trigger::Now), &clock(500, 0)).unwrap(), Some(100));
+        let slot_trigger = Trigger::Slot { slot: 300 };
+        assert_eq!(kickoff_due_slot(&thread(slot_trigger), &clock(500, 0)).unwrap(), Some(300));
+
+        // Triggers which became due before the thread's last exec date from the exec.
+        let slot_trigger = Trigger::Slot { slot: 50 };
+        assert_eq!(kickoff_due_slot(&thread(slot_trigger), &clock(500, 0)).unwrap(), Some(100));
+    }
+
+    #[test]
+    fn kickoff_due_slot_of_epoch_triggers() {
+        let epoch_trigger = Trigger::Epoch { epoch: 2 };
+        assert_eq!(kickoff_due_slot(&thread(epoch_trigger), &clock(2_500, 0)).unwrap(), Some(2_000));
+        let every_epoch_trigger = Trigger::EveryEpoch { offset_slots: 10 };
+        assert_eq!(kickoff_due_slot(&thread(every_epoch_trigger), &clock(3_500, 0)).unwrap(), Some(3_010));
+    }
+
+    #[test]
+    fn kickoff_due_slot_of_time_triggers() {
+        // Ten seconds overdue is 25 slots at the default slot time.
+        let timestamp_trigger = Trigger::Timestamp { unix_ts: 100 };
+        assert_eq!(kickoff_due_slot(&thread(timestamp_trigger), &clock(1_000, 110)).unwrap(), Some(975));
+        let cron_trigger = Trigger::Cron {
+            schedule: "*/10 * * * * * *".into(),
+            skippable: true,
+        };
+        assert_eq!(kickoff_due_slot(&thread(cron_trigger), &clock(1_000, 20)).unwrap(), Some(975));
+    }
 }
@@ -1,5 +1,7 @@
 use crate::{errors::*, state::*};
 
+use antegen_network_program::state::{Config, Pool};
+
 use anchor_lang::{
     prelude::*,
     solana_program::system_program,
@@ -30,11 +32,21 @@ pub struct ThreadUpdate<'info> {
             has_one = authority,
         )]
     pub thread: Account<'info, Thread>,
+
+    /// The pool to move the thread to, required when updating the thread's pool.
+    /// Listed last so callers which do not update the pool may omit it.
+    pub pool: Option<Account<'info, Pool>>,
+
+    /// The network config, required when setting a fee token account.
+    #[account(address = Config::pubkey())]
+    pub config: Option<Account<'info, Config>>,
 }
 
 pub fn handler(ctx: Context<ThreadUpdate>, settings: ThreadSettings) -> Result<()> {
     // Get accounts
     let authority = &ctx.accounts.authority;
+    let config = &ctx.accounts.config;
+    let pool = &ctx.accounts.pool;
     let thread = &mut ctx.accounts.thread;
     let system_program = &ctx.accounts.system_program;
 
@@ -43,11 +55,39 @@ pub fn handler(ctx: Context<ThreadUpdate>, settings: ThreadSettings) -> Result<(
         thread.fee = fee;
     }
 
+    // If provided, update the fee model.
+    if let Some(fee_model) = settings.fee_model {
+        thread.fee_model = fee_model;
+    }
+
+    // If provided, update the token account used to pay worker fees.
+    // The account's owner and mint are verified when fees are paid, but the network must accept a fee token.
+    if let Some(fee_token_account) = settings.fee_token_account {
+        require!(
+            fee_token_account.is_none()
+                || config
+                    .as_ref()
+                    .is_some_and(|config| config.fee_mint.is_some()),
+            AntegenThreadError::InvalidFeeTokenAccount
+        );
+        thread.fee_token_account = fee_token_account;
+    }
+
     // If provided, update the thread's instruction set.
     if let Some(instructions) = settings.instructions {
         thread.instructions = instructions;
     }
 
+    // If provided, update the pool whose workers serve the thread.
+    // The pool account must exist, or no worker could execute the thread.
+    if let Some(pool_id) = settings.pool_id {
+        require!(
+            pool.as_ref().is_some_and(|pool| pool.id.eq(&pool_id)),
+            AntegenThreadError::InvalidPool
+        );
+        thread.pool_id = pool_id;
+    }
+
     // If provided, update the rate limit.
     if let Some(rate_limit) = settings.rate_limit {
         thread.rate_limit = rate_limit;
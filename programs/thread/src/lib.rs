@@ -71,6 +71,12 @@ pub mod thread_program {
         get_crate_info::handler(ctx)
     }
 
+    /// Evaluates a thread's trigger and rate limit without mutating state.
+    /// Returns the result via `sol_set_return_data/sol_get_return_data`.
+    pub fn thread_check(ctx: Context<ThreadCheck>) -> Result<ThreadCheckResponse> {
+        thread_check::handler(ctx)
+    }
+
     /// Executes the next instruction on thread.
     pub fn thread_exec(ctx: Context<ThreadExec>) -> Result<()> {
         thread_exec::handler(ctx)
@@ -100,6 +106,24 @@ pub mod thread_program {
         thread_instruction_add::handler(ctx, instruction)
     }
 
+    /// Inserts a new instruction into the thread's instruction set at the provided index.
+    pub fn thread_instruction_insert(
+        ctx: Context<ThreadInstructionInsert>,
+        index: u64,
+        instruction: SerializableInstruction,
+    ) -> Result<()> {
+        thread_instruction_insert::handler(ctx, index, instruction)
+    }
+
+    /// Moves an instruction within the thread's instruction set from one index to another.
+    pub fn thread_instruction_move(
+        ctx: Context<ThreadInstructionMove>,
+        from: u64,
+        to: u64,
+    ) -> Result<()> {
+        thread_instruction_move::handler(ctx, from, to)
+    }
+
     /// Removes an instruction to the thread's instruction set at the provied index.
     pub fn thread_instruction_remove(
         ctx: Context<ThreadInstructionRemove>,
@@ -108,6 +132,15 @@ pub mod thread_program {
         thread_instruction_remove::handler(ctx, index)
     }
 
+    /// Replaces the instruction at the provided index of the thread's instruction set.
+    pub fn thread_instruction_replace(
+        ctx: Context<ThreadInstructionReplace>,
+        index: u64,
+        instruction: SerializableInstruction,
+    ) -> Result<()> {
+        thread_instruction_replace::handler(ctx, index, instruction)
+    }
+
     /// Kicks off a thread if its trigger condition is active.
     pub fn thread_kickoff(ctx: Context<ThreadKickoff>) -> Result<()> {
         thread_kickoff::handler(ctx)
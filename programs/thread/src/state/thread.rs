@@ -1,6 +1,7 @@
 use std::mem::size_of;
 
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use antegen_network_program::state::THREAD_ASSIGNMENT_WINDOW;
 use antegen_utils::thread::{ClockData, SerializableInstruction, Trigger};
 
 pub const SEED_THREAD: &[u8] = b"thread";
@@ -36,6 +37,14 @@ pub struct Thread {
     pub rate_limit: u64,
     /// The triggering event to kickoff a thread.
     pub trigger: Trigger,
+    /// The token account, owned by the thread, used to pay worker fees in the network's fee token.
+    /// If not set, worker fees are paid in lamports from the thread's balance.
+    /// Appended after the original fields so existing thread accounts keep their layout.
+    pub fee_token_account: Option<Pubkey>,
+    /// How the worker fee is calculated for each exec.
+    pub fee_model: FeeModel,
+    /// The id of the worker pool whose members may collect fees for executing this thread.
+    pub pool_id: u64,
 }
 
 impl Thread {
@@ -50,6 +59,15 @@ impl Thread {
         )
         .0
     }
+
+    /// The slot the thread's pending work became due at, used to assign it to a pool member.
+    /// An exec is due from the thread's last exec, and a kickoff from the start of the current assignment window.
+    pub fn due_slot(&self, slot: u64) -> u64 {
+        match (&self.next_instruction, self.exec_context) {
+            (Some(_), Some(exec_context)) => exec_context.last_exec_at,
+            _ => slot - slot % THREAD_ASSIGNMENT_WINDOW,
+        }
+    }
 }
 
 impl PartialEq for Thread {
@@ -151,12 +169,83 @@ pub enum TriggerContext {
     Pyth { price: i64 },
 }
 
+/// The models for calculating the fee paid to workers per exec.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeeModel {
+    /// Pay the thread's flat fee on every exec.
+    #[default]
+    Flat,
+
+    /// Pay a fee proportional to the compute units consumed by the exec.
+    ComputeUnits {
+        /// The number of lamports paid per 1,000 compute units.
+        lamports_per_1k_cu: u64,
+    },
+
+    /// Pay the thread's flat fee plus a fee proportional to the compute units consumed by the exec.
+    FlatPlusComputeUnits {
+        /// The number of lamports paid per 1,000 compute units.
+        lamports_per_1k_cu: u64,
+    },
+}
+
+impl FeeModel {
+    /// Calculate the fee owed for an exec, given the thread's flat fee and the compute units consumed.
+    pub fn fee(&self, flat_fee: u64, compute_units: u64) -> Option<u64> {
+        let compute_fee = |lamports_per_1k_cu: u64| {
+            compute_units
+                .checked_mul(lamports_per_1k_cu)
+                .map(|fee| fee.div_ceil(1_000))
+        };
+        match self {
+            FeeModel::Flat => Some(flat_fee),
+            FeeModel::ComputeUnits { lamports_per_1k_cu } => compute_fee(*lamports_per_1k_cu),
+            FeeModel::FlatPlusComputeUnits { lamports_per_1k_cu } => {
+                flat_fee.checked_add(compute_fee(*lamports_per_1k_cu)?)
+            }
+        }
+    }
+}
+
 /// The properties of threads which are updatable.
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ThreadSettings {
     pub fee: Option<u64>,
+    pub fee_model: Option<FeeModel>,
+    pub fee_token_account: Option<Option<Pubkey>>,
     pub instructions: Option<Vec<SerializableInstruction>>,
     pub name: Option<String>,
+    pub pool_id: Option<u64>,
     pub rate_limit: Option<u64>,
     pub trigger: Option<Trigger>,
 }
+
+/// The result of evaluating a thread's trigger and rate limit, returned by `thread_check`.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq, Eq)]
+pub struct ThreadCheckResponse {
+    /// Whether a worker could kickoff or exec the thread right now.
+    pub is_due: bool,
+    /// The unix timestamp the trigger is waiting for, if it is time-based.
+    pub next_timestamp: Option<i64>,
+    /// The slot the trigger is waiting for, if it is slot-based.
+    pub next_slot: Option<u64>,
+    /// The reason the thread is not due, if any.
+    pub reason: Option<ThreadCheckFailure>,
+    /// The pool member assigned to the thread's current run, if the pool and registry were passed and the pool has members.
+    pub assigned_worker: Option<Pubkey>,
+}
+
+/// The reasons a thread may not be due.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreadCheckFailure {
+    /// The thread is paused.
+    Paused,
+    /// The thread has reached its rate limit for the current slot.
+    RateLimitExceeded,
+    /// The trigger condition has not been met.
+    TriggerConditionFailed,
+    /// The thread's exec context does not match its trigger.
+    InvalidThreadState,
+    /// The thread's cron schedule cannot be parsed.
+    InvalidSchedule,
+}
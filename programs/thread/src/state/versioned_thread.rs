@@ -1,7 +1,7 @@
 use anchor_lang::{prelude::*, AccountDeserialize};
 
 use crate::{
-    ClockData, ExecContext, SerializableInstruction, Thread as ThreadV1, Trigger,
+    ClockData, ExecContext, FeeModel, SerializableInstruction, Thread as ThreadV1, Trigger,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -22,12 +22,36 @@ impl VersionedThread {
         }
     }
 
+    pub fn due_slot(&self, slot: u64) -> u64 {
+        match self {
+            Self::V1(t) => t.due_slot(slot),
+        }
+    }
+
     pub fn exec_context(&self) -> Option<ExecContext> {
         match self {
             Self::V1(t) => t.exec_context,
         }
     }
 
+    pub fn fee(&self) -> u64 {
+        match self {
+            Self::V1(t) => t.fee,
+        }
+    }
+
+    pub fn fee_model(&self) -> FeeModel {
+        match self {
+            Self::V1(t) => t.fee_model,
+        }
+    }
+
+    pub fn fee_token_account(&self) -> Option<Pubkey> {
+        match self {
+            Self::V1(t) => t.fee_token_account,
+        }
+    }
+
     pub fn id(&self) -> Vec<u8> {
         match self {
             Self::V1(t) => t.id.clone(),
@@ -58,6 +82,12 @@ impl VersionedThread {
         }
     }
 
+    pub fn pool_id(&self) -> u64 {
+        match self {
+            Self::V1(t) => t.pool_id,
+        }
+    }
+
     pub fn rate_limit(&self) -> u64 {
         match self {
             Self::V1(t) => t.rate_limit,
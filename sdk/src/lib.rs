@@ -4,8 +4,9 @@ pub use antegen_thread_program::ID;
 
 pub mod state {
     pub use antegen_thread_program::state::{
-        ClockData, ExecContext, SerializableAccount, SerializableInstruction, Thread,
-        ThreadAccount, ThreadResponse, ThreadSettings, Trigger, TriggerContext,
+        ClockData, ExecContext, FeeModel, SerializableAccount, SerializableInstruction, Thread,
+        ThreadAccount, ThreadCheckFailure, ThreadCheckResponse, ThreadResponse, ThreadSettings,
+        Trigger, TriggerContext,
     };
 }
 
@@ -18,7 +19,8 @@ pub mod cpi {
     use anchor_lang::prelude::{CpiContext, Result};
 
     pub use antegen_thread_program::cpi::accounts::{
-        ThreadCreate, ThreadDelete, ThreadPause, ThreadReset, ThreadResume, ThreadUpdate,
+        ThreadCreate, ThreadDelete, ThreadInstructionInsert, ThreadInstructionMove,
+        ThreadInstructionReplace, ThreadPause, ThreadReset, ThreadResume, ThreadUpdate,
         ThreadWithdraw,
     };
     use antegen_thread_program::ThreadId;
@@ -39,6 +41,30 @@ pub mod cpi {
         antegen_thread_program::cpi::thread_delete(ctx)
     }
 
+    pub fn thread_instruction_insert<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ThreadInstructionInsert<'info>>,
+        index: u64,
+        instruction: crate::state::SerializableInstruction,
+    ) -> Result<()> {
+        antegen_thread_program::cpi::thread_instruction_insert(ctx, index, instruction)
+    }
+
+    pub fn thread_instruction_move<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ThreadInstructionMove<'info>>,
+        from: u64,
+        to: u64,
+    ) -> Result<()> {
+        antegen_thread_program::cpi::thread_instruction_move(ctx, from, to)
+    }
+
+    pub fn thread_instruction_replace<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ThreadInstructionReplace<'info>>,
+        index: u64,
+        instruction: crate::state::SerializableInstruction,
+    ) -> Result<()> {
+        antegen_thread_program::cpi::thread_instruction_replace(ctx, index, instruction)
+    }
+
     pub fn thread_pause<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, ThreadPause<'info>>,
     ) -> Result<()> {
@@ -87,6 +87,12 @@ pub enum Trigger {
         /// The limit price to compare the Pyth feed to. 
         limit: i64,
     },
+
+    /// Allows a thread to be kicked off once every epoch.
+    EveryEpoch {
+        /// The number of slots into the epoch to wait before kicking off the thread.
+        offset_slots: u64,
+    },
 }
 
 /// Operators for describing how to compare two values to one another.  
@@ -227,3 +233,55 @@ impl SerializableAccount {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Triggers are stored in thread accounts, so each variant's tag must never change.
+    #[test]
+    fn trigger_tags_are_pinned() {
+        let triggers = [
+            Trigger::Account {
+                address: Pubkey::default(),
+                offset: 0,
+                size: 0,
+            },
+            Trigger::Cron {
+                schedule: String::new(),
+                skippable: false,
+            },
+            Trigger::Now,
+            Trigger::Slot { slot: 0 },
+            Trigger::Epoch { epoch: 0 },
+            Trigger::Timestamp { unix_ts: 0 },
+            Trigger::Pyth {
+                price_feed: Pubkey::default(),
+                equality: Equality::GreaterThanOrEqual,
+                limit: 0,
+            },
+            Trigger::EveryEpoch { offset_slots: 0 },
+        ];
+        for (tag, trigger) in triggers.iter().enumerate() {
+            assert_eq!(trigger.try_to_vec().unwrap()[0], tag as u8);
+        }
+    }
+
+    #[test]
+    fn trigger_round_trip() {
+        let trigger = Trigger::EveryEpoch { offset_slots: 42 };
+        let data = trigger.try_to_vec().unwrap();
+        assert_eq!(data, [7, 42, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Trigger::try_from_slice(&data).unwrap(), trigger);
+
+        let trigger = Trigger::Pyth {
+            price_feed: Pubkey::new_from_array([1; 32]),
+            equality: Equality::LessThanOrEqual,
+            limit: -5,
+        };
+        let data = trigger.try_to_vec().unwrap();
+        assert_eq!(data.len(), 1 + 32 + 1 + 8);
+        assert_eq!(data[33], 1);
+        assert_eq!(Trigger::try_from_slice(&data).unwrap(), trigger);
+    }
+}